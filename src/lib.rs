@@ -1,4 +1,8 @@
 mod modules;
 
 pub use modules::token;
-pub use modules::algorithm;
\ No newline at end of file
+pub use modules::algorithm;
+pub use modules::jwk;
+
+#[cfg(feature = "jwe")]
+pub use modules::jwe;
\ No newline at end of file
@@ -1,2 +1,6 @@
 pub mod token;
-pub mod algorithm;
\ No newline at end of file
+pub mod algorithm;
+pub mod jwk;
+
+#[cfg(feature = "jwe")]
+pub mod jwe;
\ No newline at end of file
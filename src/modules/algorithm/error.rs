@@ -0,0 +1,91 @@
+use thiserror::Error;
+
+/// Error returned by the types that can hold any algorithm, like
+/// [AnyAlgorithm](crate::algorithm::AnyAlgorithm). Wraps the error of the algorithm that was used.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct AlgorithmError(Box<dyn std::error::Error + Send + Sync>);
+
+impl AlgorithmError {
+    pub fn new(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        AlgorithmError(Box::new(error))
+    }
+}
+
+/// Error returned when creating one of the HMAC algorithms.
+#[cfg(feature = "hmac")]
+#[derive(Debug, Error)]
+pub enum HmacKeyError {
+    #[error("Invalid key length")]
+    InvalidLength,
+
+    /// The secret is a PEM encoded (asymmetric) key. Using for example an RSA public key as the
+    /// HMAC secret is a well known algorithm confusion attack, as the public key is not secret.
+    #[error("PEM encoded keys cannot be used as an HMAC secret")]
+    PemEncodedKey,
+}
+
+#[cfg(feature = "hmac")]
+impl HmacKeyError {
+    /// Checks the secret before it's used for one of the HMAC algorithms.
+    pub(crate) fn check_secret(key: &[u8]) -> Result<(), HmacKeyError> {
+        if key.trim_ascii_start().starts_with(b"-----BEGIN") {
+            return Err(HmacKeyError::PemEncodedKey);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "hmac")]
+impl From<hmac::digest::InvalidLength> for HmacKeyError {
+    fn from(_: hmac::digest::InvalidLength) -> Self {
+        HmacKeyError::InvalidLength
+    }
+}
+
+/// Error returned when loading a key from PEM or DER, or when generating or exporting a key.
+#[cfg(feature = "pkcs8")]
+#[derive(Debug, Error)]
+pub enum KeyError {
+    #[error("Invalid PEM: {0}")]
+    Pem(pkcs8::der::pem::Error),
+
+    #[error("PEM label `{0}` cannot be used for this algorithm")]
+    UnexpectedLabel(String),
+
+    /// The PEM is an `ENCRYPTED PRIVATE KEY`, which needs to be loaded together with the passphrase
+    /// using `from_encrypted_pem`.
+    #[error("The key is encrypted and needs a passphrase")]
+    EncryptedKey,
+
+    #[cfg(feature = "encrypted-pem")]
+    #[error("The passphrase of the encrypted key is incorrect")]
+    WrongPassphrase,
+
+    /// The key is encrypted using a scheme other than PBES2 with PBKDF2 or scrypt and AES-CBC.
+    #[cfg(feature = "encrypted-pem")]
+    #[error("The key is encrypted using an unsupported algorithm")]
+    UnsupportedEncryption,
+
+    /// The key is a valid PKCS#8 or SPKI key, but for a different key type or curve.
+    #[error("The key is not a {0} key")]
+    KeyTypeMismatch(&'static str),
+
+    /// RSA keys must be at least 2048 bits as required by
+    /// [RFC 7518 section 3.3](https://www.rfc-editor.org/rfc/rfc7518#section-3.3). This is checked
+    /// both when generating keys and when loading them.
+    #[cfg(feature = "rsa")]
+    #[error("RSA keys must be at least 2048 bits, got {0} bits")]
+    KeyTooSmall(usize),
+
+    #[error("The key could not be decoded")]
+    InvalidKey,
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<pkcs8::der::pem::Error> for KeyError {
+    fn from(error: pkcs8::der::pem::Error) -> Self {
+        KeyError::Pem(error)
+    }
+}
\ No newline at end of file
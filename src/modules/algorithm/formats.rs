@@ -0,0 +1,349 @@
+use pkcs8::{AlgorithmIdentifierRef, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding, ObjectIdentifier, PrivateKeyInfo, SubjectPublicKeyInfoRef};
+use pkcs8::der::pem;
+use pkcs8::der::zeroize::Zeroizing;
+
+#[cfg(feature = "encrypted-pem")]
+use pkcs8::{pkcs5, EncryptedPrivateKeyInfo};
+
+#[cfg(feature = "encrypted-pem")]
+use pkcs8::der::ErrorKind;
+use crate::algorithm::KeyError;
+
+/// The key type used by an algorithm, identified by the algorithm (and curve) OID in PKCS#8 and
+/// SPKI encoded keys.
+pub(crate) struct KeyType {
+    name: &'static str,
+    oid: ObjectIdentifier,
+    curve: Option<ObjectIdentifier>,
+}
+
+impl KeyType {
+    fn check(&self, algorithm: &AlgorithmIdentifierRef) -> Result<(), KeyError> {
+        let curve = self.curve
+            .and_then(|_| algorithm.parameters_oid().ok());
+
+        if algorithm.oid != self.oid || curve != self.curve {
+            return Err(KeyError::KeyTypeMismatch(self.name));
+        }
+
+        Ok(())
+    }
+}
+
+/// A private key that can be loaded from PKCS#8 and, for keys that have one, from the key type
+/// specific encoding like PKCS#1 or SEC1.
+pub(crate) trait PrivateKeyFormat: DecodePrivateKey {
+    const KEY_TYPE: KeyType;
+
+    /// The PEM label of the key type specific encoding.
+    const LABEL: Option<&'static str>;
+
+    fn from_specific_der(der: &[u8]) -> Result<Self, KeyError>;
+
+    /// Checks requirements on the key that the encoding does not cover, like the minimum size of
+    /// RSA keys.
+    fn validate(&self) -> Result<(), KeyError> {
+        Ok(())
+    }
+}
+
+/// A public key that can be loaded from SPKI and, for keys that have one, from the key type
+/// specific encoding like PKCS#1.
+pub(crate) trait PublicKeyFormat: DecodePublicKey {
+    const KEY_TYPE: KeyType;
+
+    /// The PEM label of the key type specific encoding.
+    const LABEL: Option<&'static str>;
+
+    fn from_specific_der(der: &[u8]) -> Result<Self, KeyError>;
+
+    /// Checks requirements on the key that the encoding does not cover, like the minimum size of
+    /// RSA keys.
+    fn validate(&self) -> Result<(), KeyError> {
+        Ok(())
+    }
+}
+
+pub(crate) fn private_key_from_pem<K: PrivateKeyFormat>(pem: &str) -> Result<K, KeyError> {
+    let (label, der) = pem::decode_vec(pem.as_bytes())?;
+
+    let key: K = match label {
+        "PRIVATE KEY" => private_key_from_pkcs8(&der)?,
+        "ENCRYPTED PRIVATE KEY" => return Err(KeyError::EncryptedKey),
+        label if Some(label) == K::LABEL => K::from_specific_der(&der)?,
+        label => return Err(KeyError::UnexpectedLabel(label.to_string())),
+    };
+
+    key.validate()?;
+    Ok(key)
+}
+
+/// Decrypts an `ENCRYPTED PRIVATE KEY` PEM, which is PKCS#8 encrypted using PBES2.
+#[cfg(feature = "encrypted-pem")]
+pub(crate) fn private_key_from_encrypted_pem<K: PrivateKeyFormat>(
+    pem: &str,
+    passphrase: impl AsRef<[u8]>,
+) -> Result<K, KeyError> {
+    let (label, der) = pem::decode_vec(pem.as_bytes())?;
+
+    if label != "ENCRYPTED PRIVATE KEY" {
+        return Err(KeyError::UnexpectedLabel(label.to_string()));
+    }
+
+    // Unknown ciphers and key derivation functions already fail while decoding the parameters.
+    let info = EncryptedPrivateKeyInfo::try_from(der.as_slice())
+        .map_err(|e| match e {
+            pkcs8::Error::Asn1(e) if matches!(e.kind(), ErrorKind::OidUnknown { .. }) => {
+                KeyError::UnsupportedEncryption
+            },
+            _ => KeyError::InvalidKey,
+        })?;
+
+    let document = info.decrypt(passphrase)
+        .map_err(|e| match e {
+            pkcs8::Error::EncryptedPrivateKey(
+                pkcs5::Error::UnsupportedAlgorithm { .. } | pkcs5::Error::NoPbes1CryptSupport
+            ) => KeyError::UnsupportedEncryption,
+            pkcs8::Error::EncryptedPrivateKey(pkcs5::Error::AlgorithmParametersInvalid { .. }) => {
+                KeyError::InvalidKey
+            },
+            // Incorrect padding or a result that isn't DER means the passphrase was wrong.
+            _ => KeyError::WrongPassphrase,
+        })?;
+
+    // A wrong passphrase can still result in valid padding, in which case the result is garbage.
+    if PrivateKeyInfo::try_from(document.as_bytes()).is_err() {
+        return Err(KeyError::WrongPassphrase);
+    }
+
+    let key: K = private_key_from_pkcs8(document.as_bytes())?;
+
+    key.validate()?;
+    Ok(key)
+}
+
+/// Detects whether the DER is PKCS#8 and otherwise tries the key type specific encoding.
+pub(crate) fn private_key_from_der<K: PrivateKeyFormat>(der: &[u8]) -> Result<K, KeyError> {
+    let key: K = match PrivateKeyInfo::try_from(der) {
+        Ok(_) => private_key_from_pkcs8(der)?,
+        Err(_) => K::from_specific_der(der)?,
+    };
+
+    key.validate()?;
+    Ok(key)
+}
+
+fn private_key_from_pkcs8<K: PrivateKeyFormat>(der: &[u8]) -> Result<K, KeyError> {
+    let info = PrivateKeyInfo::try_from(der)
+        .map_err(|_| KeyError::InvalidKey)?;
+
+    K::KEY_TYPE.check(&info.algorithm)?;
+
+    K::from_pkcs8_der(der)
+        .map_err(|_| KeyError::InvalidKey)
+}
+
+pub(crate) fn public_key_from_pem<K: PublicKeyFormat>(pem: &str) -> Result<K, KeyError> {
+    let (label, der) = pem::decode_vec(pem.as_bytes())?;
+
+    let key: K = match label {
+        "PUBLIC KEY" => public_key_from_spki(&der)?,
+        label if Some(label) == K::LABEL => K::from_specific_der(&der)?,
+        label => return Err(KeyError::UnexpectedLabel(label.to_string())),
+    };
+
+    key.validate()?;
+    Ok(key)
+}
+
+/// Detects whether the DER is SPKI and otherwise tries the key type specific encoding.
+pub(crate) fn public_key_from_der<K: PublicKeyFormat>(der: &[u8]) -> Result<K, KeyError> {
+    let key: K = match SubjectPublicKeyInfoRef::try_from(der) {
+        Ok(_) => public_key_from_spki(der)?,
+        Err(_) => K::from_specific_der(der)?,
+    };
+
+    key.validate()?;
+    Ok(key)
+}
+
+fn public_key_from_spki<K: PublicKeyFormat>(der: &[u8]) -> Result<K, KeyError> {
+    let info = SubjectPublicKeyInfoRef::try_from(der)
+        .map_err(|_| KeyError::InvalidKey)?;
+
+    K::KEY_TYPE.check(&info.algorithm)?;
+
+    K::from_public_key_der(der)
+        .map_err(|_| KeyError::InvalidKey)
+}
+
+/// The minimum size of RSA keys from
+/// [RFC 7518 section 3.3](https://www.rfc-editor.org/rfc/rfc7518#section-3.3).
+#[cfg(feature = "rsa")]
+pub(crate) const MIN_RSA_KEY_BITS: usize = 2048;
+
+/// Fails with [KeyError::KeyTooSmall] if the RSA key is smaller than [MIN_RSA_KEY_BITS].
+#[cfg(feature = "rsa")]
+pub(crate) fn check_rsa_key_size(key: &impl rsa::traits::PublicKeyParts) -> Result<(), KeyError> {
+    let bits = key.n().bits();
+
+    if bits < MIN_RSA_KEY_BITS {
+        return Err(KeyError::KeyTooSmall(bits));
+    }
+
+    Ok(())
+}
+
+/// Generates an RSA key with a modulus of `bits` bits.
+#[cfg(feature = "rsa")]
+pub(crate) fn generate_rsa_key(bits: usize) -> Result<rsa::RsaPrivateKey, KeyError> {
+    if bits < MIN_RSA_KEY_BITS {
+        return Err(KeyError::KeyTooSmall(bits));
+    }
+
+    rsa::RsaPrivateKey::new(&mut rand_core::OsRng, bits)
+        .map_err(|_| KeyError::InvalidKey)
+}
+
+/// Encodes the private key as a PKCS#8 (`PRIVATE KEY`) PEM, which can be loaded again using
+/// [private_key_from_pem].
+pub(crate) fn private_key_to_pem(key: &impl EncodePrivateKey) -> Result<Zeroizing<String>, KeyError> {
+    key.to_pkcs8_pem(LineEnding::LF)
+        .map_err(|_| KeyError::InvalidKey)
+}
+
+/// Encodes the public key as an SPKI (`PUBLIC KEY`) PEM.
+pub(crate) fn public_key_to_pem(key: &impl EncodePublicKey) -> Result<String, KeyError> {
+    key.to_public_key_pem(LineEnding::LF)
+        .map_err(|_| KeyError::InvalidKey)
+}
+
+#[cfg(feature = "rsa")]
+mod rsa_formats {
+    use rsa::{RsaPrivateKey, RsaPublicKey};
+    use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+    use super::*;
+
+    const RSA: KeyType = KeyType {
+        name: "RSA",
+        oid: ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1"),
+        curve: None,
+    };
+
+    impl PrivateKeyFormat for RsaPrivateKey {
+        const KEY_TYPE: KeyType = RSA;
+        const LABEL: Option<&'static str> = Some("RSA PRIVATE KEY");
+
+        fn from_specific_der(der: &[u8]) -> Result<Self, KeyError> {
+            RsaPrivateKey::from_pkcs1_der(der)
+                .map_err(|_| KeyError::InvalidKey)
+        }
+
+        fn validate(&self) -> Result<(), KeyError> {
+            check_rsa_key_size(self)
+        }
+    }
+
+    impl PublicKeyFormat for RsaPublicKey {
+        const KEY_TYPE: KeyType = RSA;
+        const LABEL: Option<&'static str> = Some("RSA PUBLIC KEY");
+
+        fn from_specific_der(der: &[u8]) -> Result<Self, KeyError> {
+            RsaPublicKey::from_pkcs1_der(der)
+                .map_err(|_| KeyError::InvalidKey)
+        }
+
+        fn validate(&self) -> Result<(), KeyError> {
+            check_rsa_key_size(self)
+        }
+    }
+}
+
+/// Implements the formats for the keys of a curve crate. Private keys can also be loaded from SEC1,
+/// public keys only from SPKI.
+#[allow(unused_macros)]
+macro_rules! ec_formats {
+    ($module:ident, $curve:ident, $name:literal, $oid:literal) => {
+        mod $module {
+            use $curve::{PublicKey, SecretKey};
+            use super::*;
+
+            const KEY_TYPE: KeyType = KeyType {
+                name: $name,
+                oid: ObjectIdentifier::new_unwrap("1.2.840.10045.2.1"),
+                curve: Some(ObjectIdentifier::new_unwrap($oid)),
+            };
+
+            impl PrivateKeyFormat for SecretKey {
+                const KEY_TYPE: KeyType = KEY_TYPE;
+                const LABEL: Option<&'static str> = Some("EC PRIVATE KEY");
+
+                fn from_specific_der(der: &[u8]) -> Result<Self, KeyError> {
+                    let key = sec1::EcPrivateKey::try_from(der)
+                        .map_err(|_| KeyError::InvalidKey)?;
+
+                    // The curve is optional in SEC1, but when it is present it must match, as the
+                    // key would otherwise be loaded for the wrong curve.
+                    let curve = key.parameters.and_then(|parameters| parameters.named_curve());
+                    if curve.is_some_and(|curve| Some(curve) != KEY_TYPE.curve) {
+                        return Err(KeyError::KeyTypeMismatch(KEY_TYPE.name));
+                    }
+
+                    SecretKey::try_from(key)
+                        .map_err(|_| KeyError::InvalidKey)
+                }
+            }
+
+            impl PublicKeyFormat for PublicKey {
+                const KEY_TYPE: KeyType = KEY_TYPE;
+                const LABEL: Option<&'static str> = None;
+
+                fn from_specific_der(_: &[u8]) -> Result<Self, KeyError> {
+                    Err(KeyError::InvalidKey)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "es256")]
+ec_formats!(p256_formats, p256, "P-256", "1.2.840.10045.3.1.7");
+
+#[cfg(feature = "es384")]
+ec_formats!(p384_formats, p384, "P-384", "1.3.132.0.34");
+
+#[cfg(feature = "es512")]
+ec_formats!(p521_formats, p521, "P-521", "1.3.132.0.35");
+
+#[cfg(feature = "es256k")]
+ec_formats!(k256_formats, k256, "secp256k1", "1.3.132.0.10");
+
+#[cfg(feature = "eddsa")]
+mod ed25519_formats {
+    use ed25519_dalek::{SigningKey, VerifyingKey};
+    use super::*;
+
+    const ED25519: KeyType = KeyType {
+        name: "Ed25519",
+        oid: ObjectIdentifier::new_unwrap("1.3.101.112"),
+        curve: None,
+    };
+
+    impl PrivateKeyFormat for SigningKey {
+        const KEY_TYPE: KeyType = ED25519;
+        const LABEL: Option<&'static str> = None;
+
+        fn from_specific_der(_: &[u8]) -> Result<Self, KeyError> {
+            Err(KeyError::InvalidKey)
+        }
+    }
+
+    impl PublicKeyFormat for VerifyingKey {
+        const KEY_TYPE: KeyType = ED25519;
+        const LABEL: Option<&'static str> = None;
+
+        fn from_specific_der(_: &[u8]) -> Result<Self, KeyError> {
+            Err(KeyError::InvalidKey)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use hmac::Mac;
+
+/// Verifies the HMAC signature of the payload. The signature is compared in constant time, so this
+/// doesn't leak how much of the signature was correct.
+pub(crate) fn verify<M: Mac + Clone>(mac: &M, payload: &str, signature: &[u8]) -> bool {
+    let mut mac = mac.clone();
+    mac.update(payload.as_bytes());
+
+    mac.verify_slice(signature).is_ok()
+}
+
+/// Checks that the algorithm rejects its own signature for the payload after changing a single bit,
+/// or after changing the length.
+#[cfg(test)]
+pub(crate) fn assert_rejects_tampered(alg: &impl crate::algorithm::JwSigner, payload: &str) {
+    let signature_bytes = alg.sign(payload);
+
+    let mut flipped_first = signature_bytes.clone();
+    flipped_first[0] ^= 1;
+
+    let mut flipped_last = signature_bytes.clone();
+    *flipped_last.last_mut().unwrap() ^= 1;
+
+    let mut extended = signature_bytes.clone();
+    extended.push(0);
+
+    let truncated = &signature_bytes[..signature_bytes.len() - 1];
+
+    assert!(alg.verify(payload, &signature_bytes).unwrap());
+    assert!(!alg.verify(payload, &flipped_first).unwrap());
+    assert!(!alg.verify(payload, &flipped_last).unwrap());
+    assert!(!alg.verify(payload, &extended).unwrap());
+    assert!(!alg.verify(payload, truncated).unwrap());
+    assert!(!alg.verify(payload, &[]).unwrap());
+}
\ No newline at end of file
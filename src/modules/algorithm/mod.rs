@@ -1,15 +1,102 @@
 mod models;
 mod traits;
+mod error;
+
+// The `pkcs8`, `encrypted-pem` and `rsa` features can be enabled without any of the algorithms that
+// use this.
+#[cfg(feature = "pkcs8")]
+#[allow(dead_code)]
+pub(crate) mod formats;
+
+#[cfg(feature = "hmac")]
+pub(crate) mod mac;
 
 pub use models::none_algorithm::NoneAlgorithm;
+pub use models::any_algorithm::AnyAlgorithm;
+pub use models::any_verifier::AnyVerifier;
 
 #[cfg(feature = "hs256")]
 pub use models::hs256_algorithm::HS256Algorithm;
 
+#[cfg(feature = "hs384")]
+pub use models::hs384_algorithm::HS384Algorithm;
+
+#[cfg(feature = "hs512")]
+pub use models::hs512_algorithm::HS512Algorithm;
+
 #[cfg(feature = "rs256")]
 pub use models::rs256_algorithm::RS256Algorithm;
 
+#[cfg(feature = "rs256")]
+pub use models::rs256_verifier::RS256Verifier;
+
+#[cfg(feature = "rs384")]
+pub use models::rs384_algorithm::RS384Algorithm;
+
+#[cfg(feature = "rs384")]
+pub use models::rs384_verifier::RS384Verifier;
+
+#[cfg(feature = "rs512")]
+pub use models::rs512_algorithm::RS512Algorithm;
+
+#[cfg(feature = "rs512")]
+pub use models::rs512_verifier::RS512Verifier;
+
+#[cfg(feature = "ps256")]
+pub use models::ps256_algorithm::PS256Algorithm;
+
+#[cfg(feature = "ps256")]
+pub use models::ps256_verifier::PS256Verifier;
+
+#[cfg(feature = "ps384")]
+pub use models::ps384_algorithm::PS384Algorithm;
+
+#[cfg(feature = "ps384")]
+pub use models::ps384_verifier::PS384Verifier;
+
+#[cfg(feature = "ps512")]
+pub use models::ps512_algorithm::PS512Algorithm;
+
+#[cfg(feature = "ps512")]
+pub use models::ps512_verifier::PS512Verifier;
+
 #[cfg(feature = "es256")]
 pub use models::es256_algorithm::ES256Algorithm;
 
-pub use traits::jw_alg::JwAlg;
\ No newline at end of file
+#[cfg(feature = "es256")]
+pub use models::es256_verifier::ES256Verifier;
+
+#[cfg(feature = "es384")]
+pub use models::es384_algorithm::ES384Algorithm;
+
+#[cfg(feature = "es384")]
+pub use models::es384_verifier::ES384Verifier;
+
+#[cfg(feature = "es512")]
+pub use models::es512_algorithm::ES512Algorithm;
+
+#[cfg(feature = "es512")]
+pub use models::es512_verifier::ES512Verifier;
+
+#[cfg(feature = "es256k")]
+pub use models::es256k_algorithm::ES256KAlgorithm;
+
+#[cfg(feature = "es256k")]
+pub use models::es256k_verifier::ES256KVerifier;
+
+#[cfg(feature = "eddsa")]
+pub use models::eddsa_algorithm::EdDSAAlgorithm;
+
+#[cfg(feature = "eddsa")]
+pub use models::eddsa_verifier::EdDSAVerifier;
+
+pub use traits::jw_alg::JwAlg;
+pub use traits::jw_signer::JwSigner;
+pub use traits::jw_verifier::JwVerifier;
+pub use error::AlgorithmError;
+
+#[cfg(feature = "hmac")]
+pub use error::HmacKeyError;
+
+#[cfg(feature = "pkcs8")]
+pub use error::KeyError;
\ No newline at end of file
@@ -1,10 +1,78 @@
 pub mod none_algorithm;
+pub mod any_algorithm;
+pub mod any_verifier;
 
 #[cfg(feature = "hs256")]
 pub mod hs256_algorithm;
 
+#[cfg(feature = "hs384")]
+pub mod hs384_algorithm;
+
+#[cfg(feature = "hs512")]
+pub mod hs512_algorithm;
+
 #[cfg(feature = "rs256")]
 pub mod rs256_algorithm;
 
+#[cfg(feature = "rs256")]
+pub mod rs256_verifier;
+
+#[cfg(feature = "rs384")]
+pub mod rs384_algorithm;
+
+#[cfg(feature = "rs384")]
+pub mod rs384_verifier;
+
+#[cfg(feature = "rs512")]
+pub mod rs512_algorithm;
+
+#[cfg(feature = "rs512")]
+pub mod rs512_verifier;
+
+#[cfg(feature = "ps256")]
+pub mod ps256_algorithm;
+
+#[cfg(feature = "ps256")]
+pub mod ps256_verifier;
+
+#[cfg(feature = "ps384")]
+pub mod ps384_algorithm;
+
+#[cfg(feature = "ps384")]
+pub mod ps384_verifier;
+
+#[cfg(feature = "ps512")]
+pub mod ps512_algorithm;
+
+#[cfg(feature = "ps512")]
+pub mod ps512_verifier;
+
+#[cfg(feature = "es256")]
+pub mod es256_algorithm;
+
 #[cfg(feature = "es256")]
-pub mod es256_algorithm;
\ No newline at end of file
+pub mod es256_verifier;
+
+#[cfg(feature = "es384")]
+pub mod es384_algorithm;
+
+#[cfg(feature = "es384")]
+pub mod es384_verifier;
+
+#[cfg(feature = "es512")]
+pub mod es512_algorithm;
+
+#[cfg(feature = "es512")]
+pub mod es512_verifier;
+
+#[cfg(feature = "es256k")]
+pub mod es256k_algorithm;
+
+#[cfg(feature = "es256k")]
+pub mod es256k_verifier;
+
+#[cfg(feature = "eddsa")]
+pub mod eddsa_algorithm;
+
+#[cfg(feature = "eddsa")]
+pub mod eddsa_verifier;
\ No newline at end of file
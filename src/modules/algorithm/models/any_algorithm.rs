@@ -0,0 +1,159 @@
+use crate::algorithm::{AlgorithmError, AnyVerifier, JwSigner, JwVerifier, NoneAlgorithm};
+
+#[cfg(feature = "hs256")]
+use crate::algorithm::HS256Algorithm;
+
+#[cfg(feature = "hs384")]
+use crate::algorithm::HS384Algorithm;
+
+#[cfg(feature = "hs512")]
+use crate::algorithm::HS512Algorithm;
+
+#[cfg(feature = "rs256")]
+use crate::algorithm::RS256Algorithm;
+
+#[cfg(feature = "rs384")]
+use crate::algorithm::RS384Algorithm;
+
+#[cfg(feature = "rs512")]
+use crate::algorithm::RS512Algorithm;
+
+#[cfg(feature = "ps256")]
+use crate::algorithm::PS256Algorithm;
+
+#[cfg(feature = "ps384")]
+use crate::algorithm::PS384Algorithm;
+
+#[cfg(feature = "ps512")]
+use crate::algorithm::PS512Algorithm;
+
+#[cfg(feature = "es256")]
+use crate::algorithm::ES256Algorithm;
+
+#[cfg(feature = "es384")]
+use crate::algorithm::ES384Algorithm;
+
+#[cfg(feature = "es512")]
+use crate::algorithm::ES512Algorithm;
+
+#[cfg(feature = "es256k")]
+use crate::algorithm::ES256KAlgorithm;
+
+#[cfg(feature = "eddsa")]
+use crate::algorithm::EdDSAAlgorithm;
+
+macro_rules! any_algorithm {
+    ($($(#[$meta:meta])* $variant:ident($algorithm:ty),)*) => {
+        /// Holds any of the signing algorithms, for when the algorithm is only known at runtime.
+        /// The `alg` used in the header when signing is the one of the algorithm that is held.
+        #[derive(Clone, Debug)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyAlgorithm {
+            $($(#[$meta])* $variant($algorithm),)*
+        }
+
+        impl JwVerifier for AnyAlgorithm {
+            type Error = AlgorithmError;
+
+            fn alg(&self) -> &str {
+                match self {
+                    $($(#[$meta])* AnyAlgorithm::$variant(algorithm) => algorithm.alg(),)*
+                }
+            }
+
+            fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+                match self {
+                    $($(#[$meta])* AnyAlgorithm::$variant(algorithm) => algorithm.verify(payload, signature)
+                        .map_err(AlgorithmError::new),)*
+                }
+            }
+        }
+
+        impl JwSigner for AnyAlgorithm {
+            fn sign(&self, payload: &str) -> Vec<u8> {
+                match self {
+                    $($(#[$meta])* AnyAlgorithm::$variant(algorithm) => algorithm.sign(payload),)*
+                }
+            }
+        }
+
+        $(
+            $(#[$meta])*
+            impl From<$algorithm> for AnyAlgorithm {
+                fn from(algorithm: $algorithm) -> Self {
+                    AnyAlgorithm::$variant(algorithm)
+                }
+            }
+        )*
+    };
+}
+
+any_algorithm! {
+    None(NoneAlgorithm),
+    #[cfg(feature = "hs256")]
+    HS256(HS256Algorithm),
+    #[cfg(feature = "hs384")]
+    HS384(HS384Algorithm),
+    #[cfg(feature = "hs512")]
+    HS512(HS512Algorithm),
+    #[cfg(feature = "rs256")]
+    RS256(RS256Algorithm),
+    #[cfg(feature = "rs384")]
+    RS384(RS384Algorithm),
+    #[cfg(feature = "rs512")]
+    RS512(RS512Algorithm),
+    #[cfg(feature = "ps256")]
+    PS256(PS256Algorithm),
+    #[cfg(feature = "ps384")]
+    PS384(PS384Algorithm),
+    #[cfg(feature = "ps512")]
+    PS512(PS512Algorithm),
+    #[cfg(feature = "es256")]
+    ES256(ES256Algorithm),
+    #[cfg(feature = "es384")]
+    ES384(ES384Algorithm),
+    #[cfg(feature = "es512")]
+    ES512(ES512Algorithm),
+    #[cfg(feature = "es256k")]
+    ES256K(ES256KAlgorithm),
+    #[cfg(feature = "eddsa")]
+    EdDSA(EdDSAAlgorithm),
+}
+
+impl AnyAlgorithm {
+    /// Returns the verify-only counterpart of the held algorithm. Symmetric algorithms are simply
+    /// cloned as they need the secret to verify.
+    pub fn verifier(&self) -> AnyVerifier {
+        match self {
+            AnyAlgorithm::None(algorithm) => AnyVerifier::None(algorithm.clone()),
+            #[cfg(feature = "hs256")]
+            AnyAlgorithm::HS256(algorithm) => AnyVerifier::HS256(algorithm.clone()),
+            #[cfg(feature = "hs384")]
+            AnyAlgorithm::HS384(algorithm) => AnyVerifier::HS384(algorithm.clone()),
+            #[cfg(feature = "hs512")]
+            AnyAlgorithm::HS512(algorithm) => AnyVerifier::HS512(algorithm.clone()),
+            #[cfg(feature = "rs256")]
+            AnyAlgorithm::RS256(algorithm) => AnyVerifier::RS256(algorithm.verifier()),
+            #[cfg(feature = "rs384")]
+            AnyAlgorithm::RS384(algorithm) => AnyVerifier::RS384(algorithm.verifier()),
+            #[cfg(feature = "rs512")]
+            AnyAlgorithm::RS512(algorithm) => AnyVerifier::RS512(algorithm.verifier()),
+            #[cfg(feature = "ps256")]
+            AnyAlgorithm::PS256(algorithm) => AnyVerifier::PS256(algorithm.verifier()),
+            #[cfg(feature = "ps384")]
+            AnyAlgorithm::PS384(algorithm) => AnyVerifier::PS384(algorithm.verifier()),
+            #[cfg(feature = "ps512")]
+            AnyAlgorithm::PS512(algorithm) => AnyVerifier::PS512(algorithm.verifier()),
+            #[cfg(feature = "es256")]
+            AnyAlgorithm::ES256(algorithm) => AnyVerifier::ES256(algorithm.verifier()),
+            #[cfg(feature = "es384")]
+            AnyAlgorithm::ES384(algorithm) => AnyVerifier::ES384(algorithm.verifier()),
+            #[cfg(feature = "es512")]
+            AnyAlgorithm::ES512(algorithm) => AnyVerifier::ES512(algorithm.verifier()),
+            #[cfg(feature = "es256k")]
+            AnyAlgorithm::ES256K(algorithm) => AnyVerifier::ES256K(algorithm.verifier()),
+            #[cfg(feature = "eddsa")]
+            AnyAlgorithm::EdDSA(algorithm) => AnyVerifier::EdDSA(algorithm.verifier()),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,114 @@
+use crate::algorithm::{AlgorithmError, JwVerifier, NoneAlgorithm};
+
+#[cfg(feature = "hs256")]
+use crate::algorithm::HS256Algorithm;
+
+#[cfg(feature = "hs384")]
+use crate::algorithm::HS384Algorithm;
+
+#[cfg(feature = "hs512")]
+use crate::algorithm::HS512Algorithm;
+
+#[cfg(feature = "rs256")]
+use crate::algorithm::RS256Verifier;
+
+#[cfg(feature = "rs384")]
+use crate::algorithm::RS384Verifier;
+
+#[cfg(feature = "rs512")]
+use crate::algorithm::RS512Verifier;
+
+#[cfg(feature = "ps256")]
+use crate::algorithm::PS256Verifier;
+
+#[cfg(feature = "ps384")]
+use crate::algorithm::PS384Verifier;
+
+#[cfg(feature = "ps512")]
+use crate::algorithm::PS512Verifier;
+
+#[cfg(feature = "es256")]
+use crate::algorithm::ES256Verifier;
+
+#[cfg(feature = "es384")]
+use crate::algorithm::ES384Verifier;
+
+#[cfg(feature = "es512")]
+use crate::algorithm::ES512Verifier;
+
+#[cfg(feature = "es256k")]
+use crate::algorithm::ES256KVerifier;
+
+#[cfg(feature = "eddsa")]
+use crate::algorithm::EdDSAVerifier;
+
+macro_rules! any_verifier {
+    ($($(#[$meta:meta])* $variant:ident($verifier:ty),)*) => {
+        /// Holds any of the algorithms that can verify signatures, including the verify-only types
+        /// that only hold a public key. Like [AnyAlgorithm](crate::algorithm::AnyAlgorithm) this is
+        /// for when the algorithm is only known at runtime.
+        #[derive(Clone, Debug)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum AnyVerifier {
+            $($(#[$meta])* $variant($verifier),)*
+        }
+
+        impl JwVerifier for AnyVerifier {
+            type Error = AlgorithmError;
+
+            fn alg(&self) -> &str {
+                match self {
+                    $($(#[$meta])* AnyVerifier::$variant(verifier) => verifier.alg(),)*
+                }
+            }
+
+            fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+                match self {
+                    $($(#[$meta])* AnyVerifier::$variant(verifier) => verifier.verify(payload, signature)
+                        .map_err(AlgorithmError::new),)*
+                }
+            }
+        }
+
+        $(
+            $(#[$meta])*
+            impl From<$verifier> for AnyVerifier {
+                fn from(verifier: $verifier) -> Self {
+                    AnyVerifier::$variant(verifier)
+                }
+            }
+        )*
+    };
+}
+
+any_verifier! {
+    None(NoneAlgorithm),
+    #[cfg(feature = "hs256")]
+    HS256(HS256Algorithm),
+    #[cfg(feature = "hs384")]
+    HS384(HS384Algorithm),
+    #[cfg(feature = "hs512")]
+    HS512(HS512Algorithm),
+    #[cfg(feature = "rs256")]
+    RS256(RS256Verifier),
+    #[cfg(feature = "rs384")]
+    RS384(RS384Verifier),
+    #[cfg(feature = "rs512")]
+    RS512(RS512Verifier),
+    #[cfg(feature = "ps256")]
+    PS256(PS256Verifier),
+    #[cfg(feature = "ps384")]
+    PS384(PS384Verifier),
+    #[cfg(feature = "ps512")]
+    PS512(PS512Verifier),
+    #[cfg(feature = "es256")]
+    ES256(ES256Verifier),
+    #[cfg(feature = "es384")]
+    ES384(ES384Verifier),
+    #[cfg(feature = "es512")]
+    ES512(ES512Verifier),
+    #[cfg(feature = "es256k")]
+    ES256K(ES256KVerifier),
+    #[cfg(feature = "eddsa")]
+    EdDSA(EdDSAVerifier),
+}
\ No newline at end of file
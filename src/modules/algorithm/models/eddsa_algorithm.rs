@@ -0,0 +1,161 @@
+use std::fmt::{Debug, Formatter};
+use ed25519_dalek::{Signature, SignatureError, Signer, SigningKey};
+use rand_core::{OsRng, RngCore};
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{EdDSAVerifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// EdDSA using Ed25519 as described in [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037).
+///
+/// New keys can be created using [EdDSAAlgorithm::generate].
+#[derive(Clone)]
+pub struct EdDSAAlgorithm {
+    inner: SigningKey,
+}
+
+impl EdDSAAlgorithm {
+    pub fn new(key: SigningKey) -> Self {
+        EdDSAAlgorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        let mut secret = Zeroizing::new([0; 32]);
+        OsRng.fill_bytes(secret.as_mut());
+
+        EdDSAAlgorithm::new(SigningKey::from_bytes(&secret))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#8 (`PRIVATE KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(EdDSAAlgorithm::new(formats::private_key_from_pem(pem)?))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(EdDSAAlgorithm::new(formats::private_key_from_der(der)?))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        Ok(EdDSAAlgorithm::new(formats::private_key_from_encrypted_pem(pem, passphrase)?))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [EdDSAAlgorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(&self.inner)
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> EdDSAVerifier {
+        EdDSAVerifier::new(self.inner.verifying_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for EdDSAAlgorithm {
+    type Error = SignatureError;
+
+    fn alg(&self) -> &str {
+        "EdDSA"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::from_slice(signature)?;
+
+        Ok(self.inner.verifying_key().verify_strict(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for EdDSAAlgorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.inner.sign(payload.as_bytes()).to_vec()
+    }
+}
+
+impl Debug for EdDSAAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EdDSAAlgorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use crate::algorithm::{EdDSAAlgorithm, JwSigner, JwVerifier};
+
+    /// The key in `test-files/eddsa.key` is the one from
+    /// [RFC 8037 appendix A.1](https://www.rfc-editor.org/rfc/rfc8037#appendix-A.1) and the payload
+    /// and signature are from [appendix A.4](https://www.rfc-editor.org/rfc/rfc8037#appendix-A.4).
+    #[test]
+    fn eddsa_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc";
+        let alg = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/eddsa.key")).unwrap();
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_string, "hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn eddsa_algorithm_can_be_loaded_from_pem() {
+        use crate::algorithm::KeyError;
+
+        let alg = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/eddsa.key")).unwrap();
+
+        // The private key `d` from RFC 8037 appendix A.1.
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(alg.key().to_bytes()), "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A");
+
+        let result = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/es256.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("Ed25519"))));
+
+        let result = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/rs256.key"));
+        assert!(matches!(result, Err(KeyError::UnexpectedLabel(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "encrypted-pem")]
+    fn eddsa_algorithm_can_be_loaded_from_encrypted_pem() {
+        use crate::algorithm::KeyError;
+
+        let pem = include_str!("../../../../test-files/eddsa-encrypted.key");
+
+        let alg = EdDSAAlgorithm::from_encrypted_pem(pem, "jsonweb").unwrap();
+        let expected = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/eddsa.key")).unwrap();
+
+        assert_eq!(alg.key().to_bytes(), expected.key().to_bytes());
+
+        let result = EdDSAAlgorithm::from_encrypted_pem(pem, b"incorrect");
+        assert!(matches!(result, Err(KeyError::WrongPassphrase)));
+    }
+
+    #[test]
+    fn eddsa_algorithm_can_be_generated_and_exported() {
+        use crate::algorithm::EdDSAVerifier;
+
+        let alg = EdDSAAlgorithm::generate();
+        assert_ne!(alg.key().to_bytes(), EdDSAAlgorithm::generate().key().to_bytes());
+
+        let loaded = EdDSAAlgorithm::from_pem(&alg.to_pem().unwrap()).unwrap();
+        assert_eq!(loaded.key().to_bytes(), alg.key().to_bytes());
+
+        let verifier = EdDSAVerifier::from_public_pem(&alg.verifier().to_public_pem().unwrap()).unwrap();
+        assert_eq!(verifier.key(), alg.verifier().key());
+    }
+}
\ No newline at end of file
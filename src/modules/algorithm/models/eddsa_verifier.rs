@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Formatter};
+use ed25519_dalek::{Signature, SignatureError, VerifyingKey};
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [EdDSAAlgorithm](crate::algorithm::EdDSAAlgorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct EdDSAVerifier {
+    inner: VerifyingKey,
+}
+
+impl EdDSAVerifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        EdDSAVerifier {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(EdDSAVerifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(EdDSAVerifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for EdDSAVerifier {
+    type Error = SignatureError;
+
+    fn alg(&self) -> &str {
+        "EdDSA"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::from_slice(signature)?;
+
+        Ok(self.inner.verify_strict(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for EdDSAVerifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EdDSAVerifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use ed25519_dalek::pkcs8::DecodePublicKey;
+    use ed25519_dalek::VerifyingKey;
+    use crate::algorithm::{EdDSAVerifier, JwVerifier};
+
+    /// Uses the public key and signature from
+    /// [RFC 8037 appendix A](https://www.rfc-editor.org/rfc/rfc8037#appendix-A).
+    #[test]
+    fn eddsa_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc";
+        let signature = "hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verifying_key = VerifyingKey::from_public_key_pem(include_str!("../../../../test-files/eddsa.pub")).unwrap();
+        let verifier = EdDSAVerifier::new(verifying_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJFZERTQSJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn eddsa_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{EdDSAAlgorithm, JwSigner, KeyError};
+
+        let payload = "hello world";
+        let alg = EdDSAAlgorithm::from_pem(include_str!("../../../../test-files/eddsa.key")).unwrap();
+        let verifier = EdDSAVerifier::from_public_pem(include_str!("../../../../test-files/eddsa.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = EdDSAVerifier::from_public_pem(include_str!("../../../../test-files/es256.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("Ed25519"))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use std::fmt::{Debug, Formatter};
+use p256::ecdsa::{Signature, VerifyingKey};
+use p256::ecdsa::signature::Verifier;
+use p256::PublicKey;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [ES256Algorithm](crate::algorithm::ES256Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct ES256Verifier {
+    inner: VerifyingKey,
+}
+
+impl ES256Verifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        ES256Verifier {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_pem(pem)?;
+        Ok(ES256Verifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Loads the public key from a DER encoded SPKI key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_der(der)?;
+        Ok(ES256Verifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES256Verifier {
+    type Error = p256::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES256"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for ES256Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES256Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use p256::ecdsa::VerifyingKey;
+    use p256::pkcs8::DecodePublicKey;
+    use crate::algorithm::{ES256Verifier, JwVerifier};
+
+    #[test]
+    fn es256_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiYWRtaW4iOnRydWUsImlhdCI6MTUxNjIzOTAyMn0";
+        let signature = "XX7zPdDrYpegeS7mBfBIUVXnqVT-XSemrGjgoZBlrN0--n94Lv03J9vzbDDJXPzxnSs_62ymIJr1zBMaoMAveA";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verifying_key = VerifyingKey::from_public_key_pem(include_str!("../../../../test-files/es256.pub")).unwrap();
+        let verifier = ES256Verifier::new(verifying_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJFUzI1NiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn es256_verifier_can_be_loaded_from_spki() {
+        use crate::algorithm::KeyError;
+
+        let pem = ES256Verifier::from_public_pem(include_str!("../../../../test-files/es256.pub")).unwrap();
+        let der = ES256Verifier::from_public_der(include_bytes!("../../../../test-files/es256-spki.der")).unwrap();
+
+        assert_eq!(pem.key(), der.key());
+
+        let result = ES256Verifier::from_public_pem(include_str!("../../../../test-files/es256k.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-256"))));
+
+        let result = ES256Verifier::from_public_der(include_bytes!("../../../../test-files/rs256-spki.der"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-256"))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use std::fmt::{Debug, Formatter};
+use k256::ecdsa::{SigningKey, Signature, signature::Signer};
+use k256::ecdsa::signature::Verifier;
+use k256::SecretKey;
+use rand_core::OsRng;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{ES256KVerifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// ECDSA using secp256k1 and SHA-256 as described in
+/// [RFC 8812 section 3.2](https://www.rfc-editor.org/rfc/rfc8812#section-3.2). Signatures are always
+/// normalized to low-S and signatures with a high S value are rejected when verifying.
+///
+/// New keys can be created using [ES256KAlgorithm::generate].
+#[derive(Clone)]
+pub struct ES256KAlgorithm {
+    inner: SigningKey,
+}
+
+impl ES256KAlgorithm {
+    pub fn new(key: SigningKey) -> Self {
+        ES256KAlgorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        ES256KAlgorithm::new(SigningKey::random(&mut OsRng))
+    }
+
+    /// Loads the private key from a PEM encoded SEC1 (`EC PRIVATE KEY`) or PKCS#8 (`PRIVATE KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_pem(pem)?;
+        Ok(ES256KAlgorithm::new(SigningKey::from(key)))
+    }
+
+    /// Loads the private key from a DER encoded SEC1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_der(der)?;
+        Ok(ES256KAlgorithm::new(SigningKey::from(key)))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_encrypted_pem(pem, passphrase)?;
+        Ok(ES256KAlgorithm::new(SigningKey::from(key)))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [ES256KAlgorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(&self.inner)
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> ES256KVerifier {
+        ES256KVerifier::new(*self.inner.verifying_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES256KAlgorithm {
+    type Error = k256::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES256K"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let verifying_key = self.inner.verifying_key();
+        let signature = Signature::try_from(signature)?;
+
+        if signature.normalize_s().is_some() {
+            return Ok(false);
+        }
+
+        Ok(verifying_key.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for ES256KAlgorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        // k256 already normalizes the signature, but this makes sure it stays that way.
+        let signature: Signature = self.inner.sign(payload.as_bytes());
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        signature.to_vec()
+    }
+}
+
+impl Debug for ES256KAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES256KAlgorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use k256::ecdsa::{Signature, SigningKey};
+    use k256::SecretKey;
+    use crate::algorithm::{JwSigner, JwVerifier};
+    use crate::algorithm::models::es256k_algorithm::ES256KAlgorithm;
+
+    fn algorithm() -> ES256KAlgorithm {
+        let secret_key = include_str!("../../../../test-files/es256k.key").parse::<SecretKey>().unwrap();
+        let signing_key = SigningKey::from(secret_key);
+
+        ES256KAlgorithm::new(signing_key)
+    }
+
+    #[test]
+    fn es256k_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzI1NksiLCJ0eXAiOiJKV1QifQ.eyJoaiI6dHJ1ZX0";
+        let alg = algorithm();
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_string, "7jrl-aSWm_DRpooiLoOZ5-9JvzRE-YTGBXVgxC5p09cWRtIl6d9YRTyHp0OG51dyuPSODE6XMVr2LxF86VJKkQ");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn es256k_algorithm_rejects_high_s_signatures() {
+        let payload = "eyJhbGciOiJFUzI1NksiLCJ0eXAiOiJKV1QifQ.eyJoaiI6dHJ1ZX0";
+        let alg = algorithm();
+
+        let signature = Signature::try_from(alg.sign(payload).as_slice()).unwrap();
+        let high_s = Signature::from_scalars(signature.r(), -*signature.s()).unwrap();
+
+        assert!(high_s.normalize_s().is_some());
+        assert!(!alg.verify(payload, &high_s.to_vec()).unwrap());
+    }
+
+    #[test]
+    fn es256k_algorithm_can_be_loaded_from_pem() {
+        use crate::algorithm::KeyError;
+
+        let payload = "hello world";
+        let alg = ES256KAlgorithm::from_pem(include_str!("../../../../test-files/es256k.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES256KAlgorithm::from_pem(include_str!("../../../../test-files/es256.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("secp256k1"))));
+
+        // SEC1 keys without the public key can only be told apart by the curve parameter.
+        let result = ES256KAlgorithm::from_pem(include_str!("../../../../test-files/es256-sec1-no-public.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("secp256k1"))));
+
+        let result = ES256KAlgorithm::from_pem(include_str!("../../../../test-files/es256-sec1.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("secp256k1"))));
+    }
+}
\ No newline at end of file
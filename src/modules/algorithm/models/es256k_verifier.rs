@@ -0,0 +1,104 @@
+use std::fmt::{Debug, Formatter};
+use k256::ecdsa::{Signature, VerifyingKey};
+use k256::ecdsa::signature::Verifier;
+use k256::PublicKey;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [ES256KAlgorithm](crate::algorithm::ES256KAlgorithm) which only needs
+/// the public key. Signatures with a high S value are rejected.
+#[derive(Clone)]
+pub struct ES256KVerifier {
+    inner: VerifyingKey,
+}
+
+impl ES256KVerifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        ES256KVerifier {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_pem(pem)?;
+        Ok(ES256KVerifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Loads the public key from a DER encoded SPKI key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_der(der)?;
+        Ok(ES256KVerifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES256KVerifier {
+    type Error = k256::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES256K"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        if signature.normalize_s().is_some() {
+            return Ok(false);
+        }
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for ES256KVerifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES256KVerifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use k256::ecdsa::VerifyingKey;
+    use k256::pkcs8::DecodePublicKey;
+    use crate::algorithm::{ES256KVerifier, JwVerifier};
+
+    #[test]
+    fn es256k_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzI1NksiLCJ0eXAiOiJKV1QifQ.eyJoaiI6dHJ1ZX0";
+        let signature = "7jrl-aSWm_DRpooiLoOZ5-9JvzRE-YTGBXVgxC5p09cWRtIl6d9YRTyHp0OG51dyuPSODE6XMVr2LxF86VJKkQ";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verifying_key = VerifyingKey::from_public_key_pem(include_str!("../../../../test-files/es256k.pub")).unwrap();
+        let verifier = ES256KVerifier::new(verifying_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJFUzI1NksiLCJ0eXAiOiJKV1QifQ.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn es256k_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, KeyError, ES256KAlgorithm};
+
+        let payload = "hello world";
+        let alg = ES256KAlgorithm::from_pem(include_str!("../../../../test-files/es256k.key")).unwrap();
+        let verifier = ES256KVerifier::from_public_pem(include_str!("../../../../test-files/es256k.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES256KVerifier::from_public_pem(include_str!("../../../../test-files/es256.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("secp256k1"))));
+    }
+}
\ No newline at end of file
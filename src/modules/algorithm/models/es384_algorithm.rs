@@ -0,0 +1,140 @@
+use std::fmt::{Debug, Formatter};
+use p384::ecdsa::{SigningKey, Signature, signature::Signer};
+use p384::ecdsa::signature::Verifier;
+use p384::SecretKey;
+use rand_core::OsRng;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{ES384Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// ECDSA using P-384 and SHA-384. New keys can be created using [ES384Algorithm::generate].
+#[derive(Clone)]
+pub struct ES384Algorithm {
+    inner: SigningKey,
+}
+
+impl ES384Algorithm {
+    pub fn new(key: SigningKey) -> Self {
+        ES384Algorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        ES384Algorithm::new(SigningKey::random(&mut OsRng))
+    }
+
+    /// Loads the private key from a PEM encoded SEC1 (`EC PRIVATE KEY`) or PKCS#8 (`PRIVATE KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_pem(pem)?;
+        Ok(ES384Algorithm::new(SigningKey::from(key)))
+    }
+
+    /// Loads the private key from a DER encoded SEC1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_der(der)?;
+        Ok(ES384Algorithm::new(SigningKey::from(key)))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_encrypted_pem(pem, passphrase)?;
+        Ok(ES384Algorithm::new(SigningKey::from(key)))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [ES384Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(&self.inner)
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> ES384Verifier {
+        ES384Verifier::new(*self.inner.verifying_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES384Algorithm {
+    type Error = p384::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES384"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let verifying_key = self.inner.verifying_key();
+        let signature = Signature::try_from(signature)?;
+
+        Ok(verifying_key.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for ES384Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        let signature: Signature = self.inner.sign(payload.as_bytes());
+        signature.to_vec()
+    }
+}
+
+impl Debug for ES384Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES384Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use p384::ecdsa::SigningKey;
+    use p384::SecretKey;
+    use crate::algorithm::{JwSigner, JwVerifier};
+    use crate::algorithm::models::es384_algorithm::ES384Algorithm;
+
+    #[test]
+    fn es384_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let secret_key = include_str!("../../../../test-files/es384.key").parse::<SecretKey>().unwrap();
+        let signing_key = SigningKey::from(secret_key);
+
+        let alg = ES384Algorithm::new(signing_key);
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_bytes.len(), 96);
+        assert_eq!(signature_string, "ykJZImV6IiIobo8Doz5poEq0pYbrQyAFbj4OwCencnL1-z7Zr6ghaeyfAlXd0-i0a_XCqiny5mR4WgX8r8VPZ-AVJGNJdapYcovqUSH3ZE7LfcCoX74OErP_w-k3OS05");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn es384_algorithm_can_be_loaded_from_pem() {
+        use crate::algorithm::KeyError;
+
+        let payload = "hello world";
+        let alg = ES384Algorithm::from_pem(include_str!("../../../../test-files/es384.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES384Algorithm::from_pem(include_str!("../../../../test-files/es256.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-384"))));
+
+        let result = ES384Algorithm::from_pem(include_str!("../../../../test-files/es256-sec1.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-384"))));
+
+        let result = ES384Algorithm::from_der(include_bytes!("../../../../test-files/es256-sec1.der"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-384"))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use std::fmt::{Debug, Formatter};
+use p384::ecdsa::{Signature, VerifyingKey};
+use p384::ecdsa::signature::Verifier;
+use p384::PublicKey;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [ES384Algorithm](crate::algorithm::ES384Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct ES384Verifier {
+    inner: VerifyingKey,
+}
+
+impl ES384Verifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        ES384Verifier {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_pem(pem)?;
+        Ok(ES384Verifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Loads the public key from a DER encoded SPKI key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_der(der)?;
+        Ok(ES384Verifier::new(VerifyingKey::from(key)))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES384Verifier {
+    type Error = p384::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES384"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for ES384Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES384Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use p384::ecdsa::VerifyingKey;
+    use p384::pkcs8::DecodePublicKey;
+    use crate::algorithm::{ES384Verifier, JwVerifier};
+
+    #[test]
+    fn es384_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "ykJZImV6IiIobo8Doz5poEq0pYbrQyAFbj4OwCencnL1-z7Zr6ghaeyfAlXd0-i0a_XCqiny5mR4WgX8r8VPZ-AVJGNJdapYcovqUSH3ZE7LfcCoX74OErP_w-k3OS05";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verifying_key = VerifyingKey::from_public_key_pem(include_str!("../../../../test-files/es384.pub")).unwrap();
+        let verifier = ES384Verifier::new(verifying_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJFUzM4NCIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn es384_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, KeyError, ES384Algorithm};
+
+        let payload = "hello world";
+        let alg = ES384Algorithm::from_pem(include_str!("../../../../test-files/es384.key")).unwrap();
+        let verifier = ES384Verifier::from_public_pem(include_str!("../../../../test-files/es384.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES384Verifier::from_public_pem(include_str!("../../../../test-files/es256.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-384"))));
+    }
+}
\ No newline at end of file
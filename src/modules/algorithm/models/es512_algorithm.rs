@@ -0,0 +1,219 @@
+use std::fmt::{Debug, Formatter};
+use ecdsa::hazmat::{bits2field, sign_prehashed};
+use p521::ecdsa::{SigningKey, Signature, VerifyingKey};
+use p521::ecdsa::signature::Verifier;
+use p521::{FieldBytes, NistP521, NonZeroScalar, SecretKey};
+use p521::elliptic_curve::PrimeField;
+use rfc6979::HmacDrbg;
+use sha2::{Digest, Sha512};
+use rand_core::OsRng;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{ES512Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// ECDSA using P-521 and SHA-512. Like the other ECDSA algorithms the signatures are deterministic,
+/// see [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979). New keys can be created using
+/// [ES512Algorithm::generate].
+#[derive(Clone)]
+pub struct ES512Algorithm {
+    inner: SigningKey,
+}
+
+impl ES512Algorithm {
+    pub fn new(key: SigningKey) -> Self {
+        ES512Algorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new random key.
+    pub fn generate() -> Self {
+        ES512Algorithm::new(SigningKey::random(&mut OsRng))
+    }
+
+    /// Loads the private key from a PEM encoded SEC1 (`EC PRIVATE KEY`) or PKCS#8 (`PRIVATE KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_pem(pem)?;
+        let signing_key = SigningKey::from_bytes(&key.to_bytes())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        Ok(ES512Algorithm::new(signing_key))
+    }
+
+    /// Loads the private key from a DER encoded SEC1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_der(der)?;
+        let signing_key = SigningKey::from_bytes(&key.to_bytes())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        Ok(ES512Algorithm::new(signing_key))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        let key: SecretKey = formats::private_key_from_encrypted_pem(pem, passphrase)?;
+        let signing_key = SigningKey::from_bytes(&key.to_bytes())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        Ok(ES512Algorithm::new(signing_key))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [ES512Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        let key = SecretKey::from_bytes(&self.inner.to_bytes())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        formats::private_key_to_pem(&key)
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> ES512Verifier {
+        ES512Verifier::new(VerifyingKey::from(&self.inner))
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES512Algorithm {
+    type Error = p521::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let verifying_key = VerifyingKey::from(&self.inner);
+        let signature = Signature::try_from(signature)?;
+
+        Ok(verifying_key.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for ES512Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        let z = bits2field::<NistP521>(&Sha512::digest(payload))
+            .expect("SHA-512 digests fit in a P-521 field element");
+
+        let d = self.inner.as_nonzero_scalar();
+        let k = rfc6979_nonce(d, &z);
+
+        let (signature, _) = sign_prehashed::<NistP521, _>(d, *k, &z)
+            .expect("k is not zero");
+
+        signature.to_vec()
+    }
+}
+
+/// Generates the nonce `k` as described in
+/// [RFC 6979 section 3.2](https://www.rfc-editor.org/rfc/rfc6979#section-3.2). The signer of the
+/// `p521` crate uses a random nonce instead, and [rfc6979::generate_k] can't be used because the
+/// digest is shorter than the curve order, so the HMAC_DRBG output is reduced to 521 bits here.
+fn rfc6979_nonce(d: &NonZeroScalar, z: &FieldBytes) -> NonZeroScalar {
+    let mut drbg = HmacDrbg::<Sha512>::new(&d.to_repr(), z, &[]);
+
+    loop {
+        let mut bits = FieldBytes::default();
+        drbg.fill_bytes(&mut bits);
+
+        // Keeps the leftmost 521 of the 528 generated bits.
+        let mut k = FieldBytes::default();
+        let mut previous = 0;
+
+        for (k, byte) in k.iter_mut().zip(bits) {
+            *k = (previous << 1) | (byte >> 7);
+            previous = byte;
+        }
+
+        if let Some(k) = NonZeroScalar::from_repr(k).into() {
+            return k;
+        }
+    }
+}
+
+impl Debug for ES512Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES512Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use p521::ecdsa::SigningKey;
+    use p521::SecretKey;
+    use crate::algorithm::{JwSigner, JwVerifier};
+    use crate::algorithm::models::es512_algorithm::ES512Algorithm;
+
+    fn algorithm() -> ES512Algorithm {
+        let secret_key = include_str!("../../../../test-files/es512.key").parse::<SecretKey>().unwrap();
+        let signing_key = SigningKey::from_bytes(&secret_key.to_bytes()).unwrap();
+
+        ES512Algorithm::new(signing_key)
+    }
+
+    /// The expected signature was created using
+    /// `openssl dgst -sha512 -sign test-files/es512.key -sigopt nonce-type:1`, which uses the
+    /// deterministic nonce from RFC 6979 as well.
+    #[test]
+    fn es512_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = algorithm();
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_string, "AFj5JT-UBwUibwoGEnTH129_7_1kDFKkeq2a1DOEZy1Rl-vDxMlN_IBLRGG2aAC7hBluQNmiR9jS_7rwjzweSzjPAAl6KhO1iI6iiQKjnE-8Uw-L9sJcN2UDyHmSBX9Jako42O0w2ucY0_u4bwhRBrPN8Cd9NjdMBuS-GwMloB8XGfhT");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    /// Signature created using `openssl dgst -sha512 -sign test-files/es512.key` and converted from
+    /// DER to the raw `r || s` form.
+    #[test]
+    fn es512_algorithm_verifies_external_signature() {
+        let payload = "eyJhbGciOiJFUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "AcgYB_BGLQKJ8jfSlCvTyq68asrvoT30KKc-m4vApDWhA_d2iQ2zcJkz_giDpQwBr9AuQLVkP76CLf4vmR4eFkPgADdQQeg2RoRn7u-xi3HLLq7m4_5CNZUgXAq9z_ajYOYMUExoYjmPHMucXmoIGiYORrfzwe3UMXkUu9GJQOtk6OWe";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verify = algorithm().verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn es512_algorithm_can_be_loaded_from_pem() {
+        use crate::algorithm::KeyError;
+
+        let payload = "hello world";
+        let alg = ES512Algorithm::from_pem(include_str!("../../../../test-files/es512.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES512Algorithm::from_pem(include_str!("../../../../test-files/es384.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-521"))));
+    }
+
+    #[test]
+    fn es512_algorithm_can_be_generated_and_exported() {
+        use crate::algorithm::ES512Verifier;
+
+        let payload = "eyJhbGciOiJFUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = ES512Algorithm::generate();
+
+        let loaded = ES512Algorithm::from_pem(&alg.to_pem().unwrap()).unwrap();
+        assert_eq!(loaded.key().to_bytes(), alg.key().to_bytes());
+
+        let verifier = ES512Verifier::from_public_pem(&alg.verifier().to_public_pem().unwrap()).unwrap();
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
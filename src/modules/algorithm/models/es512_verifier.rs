@@ -0,0 +1,110 @@
+use std::fmt::{Debug, Formatter};
+use p521::ecdsa::{Signature, VerifyingKey};
+use p521::ecdsa::signature::Verifier;
+use p521::PublicKey;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [ES512Algorithm](crate::algorithm::ES512Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct ES512Verifier {
+    inner: VerifyingKey,
+}
+
+impl ES512Verifier {
+    pub fn new(key: VerifyingKey) -> Self {
+        ES512Verifier {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) key. Fails with
+    /// [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_pem(pem)?;
+        let verifying_key = VerifyingKey::from_affine(*key.as_affine())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        Ok(ES512Verifier::new(verifying_key))
+    }
+
+    /// Loads the public key from a DER encoded SPKI key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: PublicKey = formats::public_key_from_der(der)?;
+        let verifying_key = VerifyingKey::from_affine(*key.as_affine())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        Ok(ES512Verifier::new(verifying_key))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        let key = PublicKey::from_affine(*self.inner.as_affine())
+            .map_err(|_| KeyError::InvalidKey)?;
+
+        formats::public_key_to_pem(&key)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey {
+        &self.inner
+    }
+}
+
+impl JwVerifier for ES512Verifier {
+    type Error = p521::ecdsa::Error;
+
+    fn alg(&self) -> &str {
+        "ES512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for ES512Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ES512Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use p521::ecdsa::VerifyingKey;
+    use p521::pkcs8::DecodePublicKey;
+    use p521::PublicKey;
+    use crate::algorithm::{ES512Verifier, JwVerifier};
+
+    #[test]
+    fn es512_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJFUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "AcgYB_BGLQKJ8jfSlCvTyq68asrvoT30KKc-m4vApDWhA_d2iQ2zcJkz_giDpQwBr9AuQLVkP76CLf4vmR4eFkPgADdQQeg2RoRn7u-xi3HLLq7m4_5CNZUgXAq9z_ajYOYMUExoYjmPHMucXmoIGiYORrfzwe3UMXkUu9GJQOtk6OWe";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = PublicKey::from_public_key_pem(include_str!("../../../../test-files/es512.pub")).unwrap();
+        let verifier = ES512Verifier::new(VerifyingKey::from_affine(*public_key.as_affine()).unwrap());
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJFUzUxMiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn es512_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, KeyError, ES512Algorithm};
+
+        let payload = "hello world";
+        let alg = ES512Algorithm::from_pem(include_str!("../../../../test-files/es512.key")).unwrap();
+        let verifier = ES512Verifier::from_public_pem(include_str!("../../../../test-files/es512.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+
+        let result = ES512Verifier::from_public_pem(include_str!("../../../../test-files/es384.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("P-521"))));
+    }
+}
\ No newline at end of file
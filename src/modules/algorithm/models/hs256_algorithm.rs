@@ -1,46 +1,67 @@
 use std::convert::Infallible;
 use std::fmt::{Debug, Formatter};
 use hmac::{Hmac, Mac};
-use hmac::digest::InvalidLength;
+use rand_core::{OsRng, RngCore};
 use sha2::Sha256;
-use crate::algorithm::JwAlg;
+use zeroize::Zeroizing;
+use crate::algorithm::{HmacKeyError, JwSigner, JwVerifier};
+use crate::algorithm::mac;
 
 #[derive(Clone)]
 pub struct HS256Algorithm {
+    key: Zeroizing<Vec<u8>>,
     inner: Hmac<Sha256>,
 }
 
 impl HS256Algorithm {
-    pub fn new(key: &[u8]) -> Result<Self, InvalidLength> {
+    /// Creates the algorithm using the given secret. PEM encoded keys are rejected, see
+    /// [HmacKeyError::PemEncodedKey].
+    pub fn new(key: &[u8]) -> Result<Self, HmacKeyError> {
+        HmacKeyError::check_secret(key)?;
+
         Ok(HS256Algorithm {
+            key: Zeroizing::new(key.to_vec()),
             inner: Hmac::<Sha256>::new_from_slice(key)?
         })
     }
+
+    /// Generates a random 256 bit secret, which is the size of the hash output and the minimum
+    /// [RFC 7518 section 3.2](https://www.rfc-editor.org/rfc/rfc7518#section-3.2) allows.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new(vec![0; 32]);
+        OsRng.fill_bytes(&mut key);
+
+        HS256Algorithm {
+            inner: Hmac::<Sha256>::new_from_slice(&key)
+                .expect("HMAC accepts keys of any length"),
+            key,
+        }
+    }
+
+    /// Returns the secret used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
 }
 
-impl JwAlg for HS256Algorithm {
+impl JwVerifier for HS256Algorithm {
     type Error = Infallible;
 
-    fn alg() -> impl AsRef<str> {
+    fn alg(&self) -> &str {
         "HS256"
     }
 
-    fn sign(&self, payload: &str) -> Vec<u8> {
-        let mut inner = self.inner.clone();
-        inner.update(payload.as_bytes());
-
-        inner.finalize().into_bytes().to_vec()
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        Ok(mac::verify(&self.inner, payload, signature))
     }
+}
 
-    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+impl JwSigner for HS256Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
         let mut inner = self.inner.clone();
         inner.update(payload.as_bytes());
 
-        let finalized = inner.finalize()
-            .into_bytes()
-            .to_vec();
-
-        Ok(signature == finalized)
+        inner.finalize().into_bytes().to_vec()
     }
 }
 
@@ -54,7 +75,8 @@ impl Debug for HS256Algorithm {
 mod tests {
     use base64::Engine;
     use base64::prelude::BASE64_URL_SAFE_NO_PAD;
-    use crate::modules::algorithm::{HS256Algorithm, JwAlg};
+    use crate::modules::algorithm::{HS256Algorithm, JwSigner, JwVerifier};
+    use crate::algorithm::mac;
 
     #[test]
     fn hs256_algorithm_works_as_expected() {
@@ -70,4 +92,26 @@ mod tests {
 
         assert!(verify);
     }
+
+    #[test]
+    fn hs256_algorithm_rejects_incorrect_signatures() {
+        let alg = HS256Algorithm::new("qwed".as_ref()).unwrap();
+
+        mac::assert_rejects_tampered(&alg, "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0");
+    }
+
+    #[test]
+    fn hs256_algorithm_generates_random_secrets() {
+        let payload = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = HS256Algorithm::generate();
+        let other = HS256Algorithm::generate();
+
+        assert_eq!(alg.key().len(), 32);
+        assert_ne!(alg.key(), other.key());
+
+        let signature_bytes = alg.sign(payload);
+
+        assert!(alg.verify(payload, &signature_bytes).unwrap());
+        assert!(!other.verify(payload, &signature_bytes).unwrap());
+    }
 }
\ No newline at end of file
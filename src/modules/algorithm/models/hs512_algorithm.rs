@@ -0,0 +1,103 @@
+use std::convert::Infallible;
+use std::fmt::{Debug, Formatter};
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+use crate::algorithm::{HmacKeyError, JwSigner, JwVerifier};
+use crate::algorithm::mac;
+
+#[derive(Clone)]
+pub struct HS512Algorithm {
+    key: Zeroizing<Vec<u8>>,
+    inner: Hmac<Sha512>,
+}
+
+impl HS512Algorithm {
+    /// Creates the algorithm using the given secret. PEM encoded keys are rejected, see
+    /// [HmacKeyError::PemEncodedKey].
+    pub fn new(key: &[u8]) -> Result<Self, HmacKeyError> {
+        HmacKeyError::check_secret(key)?;
+
+        Ok(HS512Algorithm {
+            key: Zeroizing::new(key.to_vec()),
+            inner: Hmac::<Sha512>::new_from_slice(key)?
+        })
+    }
+
+    /// Generates a random 512 bit secret, which is the size of the hash output and the minimum
+    /// [RFC 7518 section 3.2](https://www.rfc-editor.org/rfc/rfc7518#section-3.2) allows.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new(vec![0; 64]);
+        OsRng.fill_bytes(&mut key);
+
+        HS512Algorithm {
+            inner: Hmac::<Sha512>::new_from_slice(&key)
+                .expect("HMAC accepts keys of any length"),
+            key,
+        }
+    }
+
+    /// Returns the secret used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl JwVerifier for HS512Algorithm {
+    type Error = Infallible;
+
+    fn alg(&self) -> &str {
+        "HS512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        Ok(mac::verify(&self.inner, payload, signature))
+    }
+}
+
+impl JwSigner for HS512Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        let mut inner = self.inner.clone();
+        inner.update(payload.as_bytes());
+
+        inner.finalize().into_bytes().to_vec()
+    }
+}
+
+impl Debug for HS512Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HS512Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use crate::modules::algorithm::{HS512Algorithm, JwSigner, JwVerifier};
+    use crate::algorithm::mac;
+
+    /// Test case 2 from [RFC 4231 section 4.3](https://www.rfc-editor.org/rfc/rfc4231#section-4.3).
+    #[test]
+    fn hs512_algorithm_works_as_expected() {
+        let payload = "what do ya want for nothing?";
+        let alg = HS512Algorithm::new("Jefe".as_ref()).unwrap();
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_string, "Fkt6e_z4GeLjlfvnO1bgo4e9ZCIugx_WECcM1-olBVSXWL91wFqZSm0DT2X48Ob9yuqxo01Ka0tjbgcKOLznNw");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn hs512_algorithm_rejects_incorrect_signatures() {
+        let alg = HS512Algorithm::new("qwed".as_ref()).unwrap();
+
+        mac::assert_rejects_tampered(&alg, "eyJhbGciOiJIUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0");
+    }
+}
\ No newline at end of file
@@ -1,21 +1,23 @@
 use std::convert::Infallible;
-use crate::algorithm::JwAlg;
+use crate::algorithm::{JwSigner, JwVerifier};
 
 #[derive(Clone, Debug)]
 pub struct NoneAlgorithm;
 
-impl JwAlg for NoneAlgorithm {
+impl JwVerifier for NoneAlgorithm {
     type Error = Infallible;
 
-    fn alg() -> impl AsRef<str> {
+    fn alg(&self) -> &str {
         "none"
     }
 
-    fn sign(&self, _: &str) -> Vec<u8> {
-        vec![]
-    }
-
     fn verify(&self, _: &str, _: &[u8]) -> Result<bool, Self::Error> {
         Ok(true)
     }
+}
+
+impl JwSigner for NoneAlgorithm {
+    fn sign(&self, _: &str) -> Vec<u8> {
+        vec![]
+    }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pss::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha256;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [PS256Algorithm](crate::algorithm::PS256Algorithm) which only needs
+/// the public key. The salt length is the digest output size, as required by
+/// [RFC 7518 section 3.5](https://www.rfc-editor.org/rfc/rfc7518#section-3.5).
+#[derive(Clone)]
+pub struct PS256Verifier {
+    inner: VerifyingKey<Sha256>,
+}
+
+impl PS256Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        PS256Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(PS256Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(PS256Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha256> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for PS256Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "PS256"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for PS256Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PS256Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{PS256Verifier, JwVerifier};
+
+    #[test]
+    fn ps256_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJQUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "P7UcPVIRzW_JBU_lKMO-BHRbI69au6XfjvxHUR1W480A0-ns8Y3MDXBcSwQiuyMkzsAB-L2nSYY0Y-dx6M_MU7WW6BVbgfWzy1nU3HFU-FmJ2rchjUibWipIt2RtZh_VUnAuQlLtu1KMyb35tbyPvgddPkk90W1gT19mho56_Ma0vU2owSyRRlUFx5hdomZgg-AAZU6BnSXCh7Ig0w3lMkdlJvuvvthmulE1MBlL7cHajH3W5ngVU3iFLudRApz3esPk3AFX0rutq64H29G4X4jsLmEu5foJmoHLNmfXNCCvbMd2tEgb1XK5Yj0uWwglYEHpPL2qPAOXrntgxZbBmg";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = PS256Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJQUzI1NiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn ps256_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, PS256Algorithm};
+
+        let payload = "hello world";
+        let alg = PS256Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let verifier = PS256Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use std::fmt::{Debug, Formatter};
+use rand_core::OsRng;
+use rsa::pss::{BlindedSigningKey, Signature};
+use rsa::RsaPrivateKey;
+use rsa::signature::{Keypair, RandomizedSigner, SignatureEncoding, Verifier};
+use sha2::Sha384;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{PS384Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// RSASSA-PSS using SHA-384 and MGF1 with SHA-384. As required by
+/// [RFC 7518 section 3.5](https://www.rfc-editor.org/rfc/rfc7518#section-3.5) the salt length is
+/// the same as the digest output, which is why this takes the private key instead of a signing key.
+#[derive(Clone)]
+pub struct PS384Algorithm {
+    inner: BlindedSigningKey<Sha384>,
+}
+
+impl PS384Algorithm {
+    pub fn new(key: RsaPrivateKey) -> Self {
+        PS384Algorithm {
+            inner: BlindedSigningKey::new(key),
+        }
+    }
+
+    /// Generates a new RSA key with a modulus of `bits` bits. Fails with [KeyError::KeyTooSmall]
+    /// if `bits` is smaller than 2048.
+    pub fn generate(bits: usize) -> Result<Self, KeyError> {
+        Ok(PS384Algorithm::new(formats::generate_rsa_key(bits)?))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#1 (`RSA PRIVATE KEY`) or PKCS#8 (`PRIVATE
+    /// KEY`) key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(PS384Algorithm::new(formats::private_key_from_pem(pem)?))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(PS384Algorithm::new(formats::private_key_from_der(der)?))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        Ok(PS384Algorithm::new(formats::private_key_from_encrypted_pem(pem, passphrase)?))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [PS384Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(AsRef::<RsaPrivateKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> PS384Verifier {
+        PS384Verifier::new(self.inner.as_ref().to_public_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &BlindedSigningKey<Sha384> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for PS384Algorithm {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "PS384"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verifying_key().verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for PS384Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.inner.sign_with_rng(&mut OsRng, payload.as_bytes()).to_vec()
+    }
+}
+
+impl Debug for PS384Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PS384Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::RsaPrivateKey;
+    use crate::algorithm::{PS384Algorithm, JwSigner, JwVerifier};
+
+    fn algorithm() -> PS384Algorithm {
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        PS384Algorithm::new(private_key)
+    }
+
+    #[test]
+    fn ps384_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJQUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = algorithm();
+
+        let signature_bytes = alg.sign(payload);
+
+        // PSS is randomized, so signing the same payload twice should not give the same signature.
+        assert_ne!(signature_bytes, alg.sign(payload));
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    /// Signature created using `openssl dgst -sha384 -sigopt rsa_padding_mode:pss -sigopt
+    /// rsa_pss_saltlen:48 -sign test-files/rs256.key`.
+    #[test]
+    fn ps384_algorithm_verifies_external_signature() {
+        let payload = "eyJhbGciOiJQUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "Lr-tFDFeOxPRDCxmzmH_4Bc9Kl6VWX_AbzKWQci2RhLBg7x5xjry8Wt4JOM_mNk-lPphrUdtDTiZACHkG3b9fpX9Gv6eFnA6sR5jphq9qwsUFT2iOaIU_zm9PietPMz7XDm_-lqbD7ipYZguyBAOAHp5DEHqVkeQa0pwyH8TUivhS17mpq_YkQuciKkUkn40769Ae090201a2V5uVg_-YT6v3HzZlg5GbEsfldBHC-__0nv96rfHDr9WAyM1nrlyJNhj5SwpyJ8uaL4q8Z59I03OJdSF6dcOYT6vte5tI2V5W5A_xt2bfJdd8Di1wRGf_L9UMwS9-7L2N3ZddXpRAA";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let alg = algorithm();
+
+        assert!(alg.verify(payload, &signature_bytes).unwrap());
+        assert!(!alg.verify("eyJhbGciOiJQUzM4NCIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn ps384_algorithm_can_be_loaded_from_pem() {
+        let payload = "hello world";
+        let alg = PS384Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
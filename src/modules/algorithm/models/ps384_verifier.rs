@@ -0,0 +1,97 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pss::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha384;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [PS384Algorithm](crate::algorithm::PS384Algorithm) which only needs
+/// the public key. The salt length is the digest output size, as required by
+/// [RFC 7518 section 3.5](https://www.rfc-editor.org/rfc/rfc7518#section-3.5).
+#[derive(Clone)]
+pub struct PS384Verifier {
+    inner: VerifyingKey<Sha384>,
+}
+
+impl PS384Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        PS384Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(PS384Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(PS384Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha384> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for PS384Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "PS384"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for PS384Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PS384Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{PS384Verifier, JwVerifier};
+
+    #[test]
+    fn ps384_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJQUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "Lr-tFDFeOxPRDCxmzmH_4Bc9Kl6VWX_AbzKWQci2RhLBg7x5xjry8Wt4JOM_mNk-lPphrUdtDTiZACHkG3b9fpX9Gv6eFnA6sR5jphq9qwsUFT2iOaIU_zm9PietPMz7XDm_-lqbD7ipYZguyBAOAHp5DEHqVkeQa0pwyH8TUivhS17mpq_YkQuciKkUkn40769Ae090201a2V5uVg_-YT6v3HzZlg5GbEsfldBHC-__0nv96rfHDr9WAyM1nrlyJNhj5SwpyJ8uaL4q8Z59I03OJdSF6dcOYT6vte5tI2V5W5A_xt2bfJdd8Di1wRGf_L9UMwS9-7L2N3ZddXpRAA";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = PS384Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJQUzM4NCIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn ps384_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, PS384Algorithm};
+
+        let payload = "hello world";
+        let alg = PS384Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let verifier = PS384Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
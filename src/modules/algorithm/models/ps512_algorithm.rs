@@ -0,0 +1,142 @@
+use std::fmt::{Debug, Formatter};
+use rand_core::OsRng;
+use rsa::pss::{BlindedSigningKey, Signature};
+use rsa::RsaPrivateKey;
+use rsa::signature::{Keypair, RandomizedSigner, SignatureEncoding, Verifier};
+use sha2::Sha512;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{PS512Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// RSASSA-PSS using SHA-512 and MGF1 with SHA-512. As required by
+/// [RFC 7518 section 3.5](https://www.rfc-editor.org/rfc/rfc7518#section-3.5) the salt length is
+/// the same as the digest output, which is why this takes the private key instead of a signing key.
+#[derive(Clone)]
+pub struct PS512Algorithm {
+    inner: BlindedSigningKey<Sha512>,
+}
+
+impl PS512Algorithm {
+    pub fn new(key: RsaPrivateKey) -> Self {
+        PS512Algorithm {
+            inner: BlindedSigningKey::new(key),
+        }
+    }
+
+    /// Generates a new RSA key with a modulus of `bits` bits. Fails with [KeyError::KeyTooSmall]
+    /// if `bits` is smaller than 2048.
+    pub fn generate(bits: usize) -> Result<Self, KeyError> {
+        Ok(PS512Algorithm::new(formats::generate_rsa_key(bits)?))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#1 (`RSA PRIVATE KEY`) or PKCS#8 (`PRIVATE
+    /// KEY`) key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(PS512Algorithm::new(formats::private_key_from_pem(pem)?))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(PS512Algorithm::new(formats::private_key_from_der(der)?))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        Ok(PS512Algorithm::new(formats::private_key_from_encrypted_pem(pem, passphrase)?))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [PS512Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(AsRef::<RsaPrivateKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> PS512Verifier {
+        PS512Verifier::new(self.inner.as_ref().to_public_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &BlindedSigningKey<Sha512> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for PS512Algorithm {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "PS512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verifying_key().verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for PS512Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.inner.sign_with_rng(&mut OsRng, payload.as_bytes()).to_vec()
+    }
+}
+
+impl Debug for PS512Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PS512Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::RsaPrivateKey;
+    use crate::algorithm::{PS512Algorithm, JwSigner, JwVerifier};
+
+    fn algorithm() -> PS512Algorithm {
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        PS512Algorithm::new(private_key)
+    }
+
+    #[test]
+    fn ps512_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJQUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = algorithm();
+
+        let signature_bytes = alg.sign(payload);
+
+        // PSS is randomized, so signing the same payload twice should not give the same signature.
+        assert_ne!(signature_bytes, alg.sign(payload));
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    /// Signature created using `openssl dgst -sha512 -sigopt rsa_padding_mode:pss -sigopt
+    /// rsa_pss_saltlen:64 -sign test-files/rs256.key`.
+    #[test]
+    fn ps512_algorithm_verifies_external_signature() {
+        let payload = "eyJhbGciOiJQUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "j9fAZZAomkD8DTMtvA5ZjBw8wsbmIDsE3ejG24D_09U6n22-D_PINlvXXEOborvWg0Y0nWYL9n3zwPEda_NpjvCY8anu4O3GoYAIyMBkhEOAdM6ZODlsG0SAJU1tWQCsPWPAScxXxh6KJhu_ELfp8n4dAO6Z5ybKzvY-Vxk2RuFfZfPA7rUpbzhNjrqkWrpEdNArrwAGsuCGzUku8ZSmp_c8kLKk2olUjYEgnQ_Y4PPjJZSvn11LjMEzGPMb2SCYnCC6wX7vdMZ-QatwIldwo032jpypHMVAW1VIVA76DBvW0h6TW5YGewXKsq2qdmQTSkCbnKmUcF-_eVJjPNLheA";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let alg = algorithm();
+
+        assert!(alg.verify(payload, &signature_bytes).unwrap());
+        assert!(!alg.verify("eyJhbGciOiJQUzUxMiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn ps512_algorithm_can_be_loaded_from_pem() {
+        let payload = "hello world";
+        let alg = PS512Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pss::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha512;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [PS512Algorithm](crate::algorithm::PS512Algorithm) which only needs
+/// the public key. The salt length is the digest output size, as required by
+/// [RFC 7518 section 3.5](https://www.rfc-editor.org/rfc/rfc7518#section-3.5).
+#[derive(Clone)]
+pub struct PS512Verifier {
+    inner: VerifyingKey<Sha512>,
+}
+
+impl PS512Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        PS512Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(PS512Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(PS512Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha512> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for PS512Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "PS512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for PS512Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PS512Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{PS512Verifier, JwVerifier};
+
+    #[test]
+    fn ps512_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJQUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "j9fAZZAomkD8DTMtvA5ZjBw8wsbmIDsE3ejG24D_09U6n22-D_PINlvXXEOborvWg0Y0nWYL9n3zwPEda_NpjvCY8anu4O3GoYAIyMBkhEOAdM6ZODlsG0SAJU1tWQCsPWPAScxXxh6KJhu_ELfp8n4dAO6Z5ybKzvY-Vxk2RuFfZfPA7rUpbzhNjrqkWrpEdNArrwAGsuCGzUku8ZSmp_c8kLKk2olUjYEgnQ_Y4PPjJZSvn11LjMEzGPMb2SCYnCC6wX7vdMZ-QatwIldwo032jpypHMVAW1VIVA76DBvW0h6TW5YGewXKsq2qdmQTSkCbnKmUcF-_eVJjPNLheA";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = PS512Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJQUzUxMiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn ps512_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, PS512Algorithm};
+
+        let payload = "hello world";
+        let alg = PS512Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let verifier = PS512Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
@@ -1,10 +1,13 @@
 use std::fmt::{Debug, Formatter};
+#[allow(unused_imports)]
 pub use rsa::pkcs1::DecodeRsaPrivateKey;
 use rsa::pkcs1v15::{Signature, SigningKey};
 use rsa::signature::{Keypair, SignatureEncoding, Signer, Verifier};
-use serde::{Deserialize, Serialize};
+use rsa::RsaPrivateKey;
 use sha2::Sha256;
-use crate::algorithm::JwAlg;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{RS256Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
 
 #[derive(Clone)]
 pub struct RS256Algorithm {
@@ -17,19 +20,58 @@ impl RS256Algorithm {
             inner: key,
         }
     }
+
+    /// Generates a new RSA key with a modulus of `bits` bits. Fails with [KeyError::KeyTooSmall]
+    /// if `bits` is smaller than 2048.
+    pub fn generate(bits: usize) -> Result<Self, KeyError> {
+        Ok(RS256Algorithm::new(SigningKey::new(formats::generate_rsa_key(bits)?)))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#1 (`RSA PRIVATE KEY`) or PKCS#8 (`PRIVATE
+    /// KEY`) key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_pem(pem)?;
+        Ok(RS256Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_der(der)?;
+        Ok(RS256Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_encrypted_pem(pem, passphrase)?;
+        Ok(RS256Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [RS256Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(AsRef::<RsaPrivateKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> RS256Verifier {
+        RS256Verifier::new(self.inner.as_ref().to_public_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey<Sha256> {
+        &self.inner
+    }
 }
 
-impl JwAlg for RS256Algorithm {
+impl JwVerifier for RS256Algorithm {
     type Error = rsa::signature::Error;
 
-    fn alg() -> impl AsRef<str> {
+    fn alg(&self) -> &str {
         "RS256"
     }
 
-    fn sign(&self, payload: &str) -> Vec<u8> {
-        self.inner.sign(payload.as_bytes()).to_vec()
-    }
-
     fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
         let signature = Signature::try_from(signature)?;
 
@@ -37,6 +79,12 @@ impl JwAlg for RS256Algorithm {
     }
 }
 
+impl JwSigner for RS256Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.inner.sign(payload.as_bytes()).to_vec()
+    }
+}
+
 impl Debug for RS256Algorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "RS256Algorithm {{ .. }}")
@@ -47,10 +95,10 @@ impl Debug for RS256Algorithm {
 mod tests {
     use base64::Engine;
     use base64::prelude::BASE64_URL_SAFE_NO_PAD;
-    use pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
     use rsa::pkcs1v15::SigningKey;
     pub use rsa::RsaPrivateKey;
-    use crate::algorithm::{JwAlg, RS256Algorithm};
+    use crate::algorithm::{RS256Algorithm, JwSigner, JwVerifier};
 
     #[test]
     fn rs256_algorithm_works_as_expected() {
@@ -69,4 +117,86 @@ mod tests {
 
         assert!(verify);
     }
+
+    #[test]
+    fn rs256_algorithm_can_be_loaded_from_pkcs1_and_pkcs8() {
+        let payload = "hello world";
+        let alg = RS256Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let signature = alg.sign(payload);
+
+        let algorithms = [
+            RS256Algorithm::from_pem(include_str!("../../../../test-files/rs256-pkcs8.key")).unwrap(),
+            RS256Algorithm::from_der(include_bytes!("../../../../test-files/rs256-pkcs1.der")).unwrap(),
+            RS256Algorithm::from_der(include_bytes!("../../../../test-files/rs256-pkcs8.der")).unwrap(),
+        ];
+
+        for alg in algorithms {
+            assert_eq!(alg.sign(payload), signature);
+        }
+    }
+
+    #[test]
+    fn rs256_algorithm_rejects_other_key_types() {
+        use crate::algorithm::KeyError;
+
+        let result = RS256Algorithm::from_pem(include_str!("../../../../test-files/es256.key"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("RSA"))));
+
+        let result = RS256Algorithm::from_pem(include_str!("../../../../test-files/es256-sec1.key"));
+        assert!(matches!(result, Err(KeyError::UnexpectedLabel(label)) if label == "EC PRIVATE KEY"));
+
+        let result = RS256Algorithm::from_pem(include_str!("../../../../test-files/rs256.pub"));
+        assert!(matches!(result, Err(KeyError::UnexpectedLabel(label)) if label == "PUBLIC KEY"));
+
+        let result = RS256Algorithm::from_der(include_bytes!("../../../../test-files/es256-sec1.der"));
+        assert!(matches!(result, Err(KeyError::InvalidKey)));
+
+        let result = RS256Algorithm::from_pem("not a pem");
+        assert!(matches!(result, Err(KeyError::Pem(_))));
+
+        let result = RS256Algorithm::from_pem(include_str!("../../../../test-files/rs1024.key"));
+        assert!(matches!(result, Err(KeyError::KeyTooSmall(1024))));
+    }
+
+    #[test]
+    #[cfg(feature = "encrypted-pem")]
+    fn rs256_algorithm_can_be_loaded_from_encrypted_pem() {
+        use crate::algorithm::KeyError;
+
+        let pem = include_str!("../../../../test-files/rs256-encrypted.key");
+        let payload = "hello world";
+
+        let alg = RS256Algorithm::from_encrypted_pem(pem, "jsonweb").unwrap();
+        let expected = RS256Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+
+        assert_eq!(alg.sign(payload), expected.sign(payload));
+
+        let result = RS256Algorithm::from_encrypted_pem(pem, "incorrect");
+        assert!(matches!(result, Err(KeyError::WrongPassphrase)));
+
+        let result = RS256Algorithm::from_pem(pem);
+        assert!(matches!(result, Err(KeyError::EncryptedKey)));
+
+        let result = RS256Algorithm::from_encrypted_pem(include_str!("../../../../test-files/es256-encrypted.key"), "jsonweb");
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("RSA"))));
+    }
+
+    #[test]
+    fn rs256_algorithm_can_be_generated_and_exported() {
+        use rsa::traits::PublicKeyParts;
+        use crate::algorithm::{KeyError, RS256Verifier};
+
+        let payload = "hello world";
+        let alg = RS256Algorithm::generate(2048).unwrap();
+
+        assert_eq!(alg.key().as_ref().size(), 256);
+
+        let loaded = RS256Algorithm::from_pem(&alg.to_pem().unwrap()).unwrap();
+        assert_eq!(loaded.sign(payload), alg.sign(payload));
+
+        let verifier = RS256Verifier::from_public_pem(&alg.verifier().to_public_pem().unwrap()).unwrap();
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+
+        assert!(matches!(RS256Algorithm::generate(1024), Err(KeyError::KeyTooSmall(1024))));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha256;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [RS256Algorithm](crate::algorithm::RS256Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct RS256Verifier {
+    inner: VerifyingKey<Sha256>,
+}
+
+impl RS256Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        RS256Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RS256Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RS256Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha256> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for RS256Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "RS256"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for RS256Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RS256Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{RS256Verifier, JwVerifier};
+
+    #[test]
+    fn rs256_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "ptH8Vc-nhm4gTl7HqaictKQyK3fxiJmSfyu-ouYlmIfyyRBIYw2tUdKxIsxgYMPXC7oV0-ShYtlUm73-q2buLoYGc52d-03RQghcVvZrag2nQCKsBBmTXFUADEaVopO65aND5h7Uif_1aQJXmX-40-V5te0fT3WSyU_1oKayxpi53_c7RXD7gDlWSXAZFDNhPopcRnq2_4FQylzFf4qbwtGWUNdJA4SGOikr1lsTrQRPGXLNXREG0PWv9GFoobQDTj9DWBG4B_cCAUVAjYUCx8BbgHSY9jeiYE_FbDykW0tRSA3XAYpf1QCPZmrCPButUixWY03FTTxsQxlJuY8r-w";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = RS256Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn rs256_verifier_can_be_loaded_from_spki_and_pkcs1() {
+        use crate::algorithm::KeyError;
+
+        let payload = "eyJhbGciOiJSUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "ptH8Vc-nhm4gTl7HqaictKQyK3fxiJmSfyu-ouYlmIfyyRBIYw2tUdKxIsxgYMPXC7oV0-ShYtlUm73-q2buLoYGc52d-03RQghcVvZrag2nQCKsBBmTXFUADEaVopO65aND5h7Uif_1aQJXmX-40-V5te0fT3WSyU_1oKayxpi53_c7RXD7gDlWSXAZFDNhPopcRnq2_4FQylzFf4qbwtGWUNdJA4SGOikr1lsTrQRPGXLNXREG0PWv9GFoobQDTj9DWBG4B_cCAUVAjYUCx8BbgHSY9jeiYE_FbDykW0tRSA3XAYpf1QCPZmrCPButUixWY03FTTxsQxlJuY8r-w";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let verifiers = [
+            RS256Verifier::from_public_pem(include_str!("../../../../test-files/rs256.pub")).unwrap(),
+            RS256Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap(),
+            RS256Verifier::from_public_der(include_bytes!("../../../../test-files/rs256-spki.der")).unwrap(),
+        ];
+
+        for verifier in verifiers {
+            assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        }
+
+        let result = RS256Verifier::from_public_pem(include_str!("../../../../test-files/es256.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTypeMismatch("RSA"))));
+
+        let result = RS256Verifier::from_public_pem(include_str!("../../../../test-files/rs256.key"));
+        assert!(matches!(result, Err(KeyError::UnexpectedLabel(_))));
+
+        let result = RS256Verifier::from_public_pem(include_str!("../../../../test-files/rs1024.pub"));
+        assert!(matches!(result, Err(KeyError::KeyTooSmall(1024))));
+    }
+}
\ No newline at end of file
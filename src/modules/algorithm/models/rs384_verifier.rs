@@ -0,0 +1,96 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha384;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [RS384Algorithm](crate::algorithm::RS384Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct RS384Verifier {
+    inner: VerifyingKey<Sha384>,
+}
+
+impl RS384Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        RS384Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RS384Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RS384Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha384> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for RS384Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "RS384"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for RS384Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RS384Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{RS384Verifier, JwVerifier};
+
+    #[test]
+    fn rs384_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJSUzM4NCIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "ASRlwk0afm69uKW9QURaoAm0CBI10SvcLpDWmGMJ6gWEWIKBz5fxWjbO_A5_sQjNL-Szyl0xhaI9CWO0nQgBkgHBZgK6XCY5wSHO0cj9XZte6n4MTmW-l4SagRXHz71lsv-v2RU3Zfys0Gw9CFky3_zNNk125XVzXUBE8mc2wrpABFBH5idhFg9Rcrr7aKdnf7Nfk4pd4tBEztQ3mRAvdI-W8-_SUeJLnAPRD7V7wv99ZgKxXsSzW3CB_lqjPGal2KqN3AwwT7D_rBu9HVJ8nR75hv4uMUtrdnTl4i44YxWGen1zaEvdIH40LD2AjWR2fMNWsEMyyV776fW0ZoOzQQ";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = RS384Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJSUzM4NCIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn rs384_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, RS384Algorithm};
+
+        let payload = "hello world";
+        let alg = RS384Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let verifier = RS384Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
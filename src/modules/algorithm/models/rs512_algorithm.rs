@@ -0,0 +1,126 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pkcs1v15::{Signature, SigningKey};
+use rsa::signature::{Keypair, SignatureEncoding, Signer, Verifier};
+use rsa::RsaPrivateKey;
+use sha2::Sha512;
+use pkcs8::der::zeroize::Zeroizing;
+use crate::algorithm::{RS512Verifier, JwSigner, JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+#[derive(Clone)]
+pub struct RS512Algorithm {
+    inner: SigningKey<Sha512>
+}
+
+impl RS512Algorithm {
+    pub fn new(key: SigningKey<Sha512>) -> Self {
+        RS512Algorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new RSA key with a modulus of `bits` bits. Fails with [KeyError::KeyTooSmall]
+    /// if `bits` is smaller than 2048.
+    pub fn generate(bits: usize) -> Result<Self, KeyError> {
+        Ok(RS512Algorithm::new(SigningKey::new(formats::generate_rsa_key(bits)?)))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#1 (`RSA PRIVATE KEY`) or PKCS#8 (`PRIVATE
+    /// KEY`) key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_pem(pem)?;
+        Ok(RS512Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_der(der)?;
+        Ok(RS512Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key, as
+    /// produced by `openssl pkcs8 -topk8`. Only PBES2 with AES-CBC is supported.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        let key: RsaPrivateKey = formats::private_key_from_encrypted_pem(pem, passphrase)?;
+        Ok(RS512Algorithm::new(SigningKey::new(key)))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [RS512Algorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(AsRef::<RsaPrivateKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the verify-only counterpart of this algorithm, which only holds the public key.
+    pub fn verifier(&self) -> RS512Verifier {
+        RS512Verifier::new(self.inner.as_ref().to_public_key())
+    }
+
+    /// Returns the signing key used for this algorithm.
+    pub fn key(&self) -> &SigningKey<Sha512> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for RS512Algorithm {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "RS512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verifying_key().verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl JwSigner for RS512Algorithm {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.inner.sign(payload.as_bytes()).to_vec()
+    }
+}
+
+impl Debug for RS512Algorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RS512Algorithm {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs1v15::SigningKey;
+    pub use rsa::RsaPrivateKey;
+    use crate::algorithm::{RS512Algorithm, JwSigner, JwVerifier};
+
+    #[test]
+    fn rs512_algorithm_works_as_expected() {
+        let payload = "eyJhbGciOiJSUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let signing_key = SigningKey::new(private_key);
+        let alg = RS512Algorithm::new(signing_key);
+
+        let signature_bytes = alg.sign(payload);
+        let signature_string = BASE64_URL_SAFE_NO_PAD.encode(&signature_bytes);
+
+        assert_eq!(signature_string, "F71BK7fEdeL2W1ZZ9JJpE3WjXb8anq-JtLNoUnccQYBW3s72ONE2nXcNZuB6wojuAlnlm_hpkGrd-ToE7tbd3IR9Fej1mt2wDvsgCUSV_80KxOchDmpCKmF2PWvSuOG58tLCSquTUQjxX-zC72SkfzDcvm4KCJU7dBMphv8aCupZ8ExmAyn8Ya4qBnyIOewRiOq8UNIUNZFkgru3KJJoM1k7n9lD-xof3CKm_W_jK0dgDBn7Co46TLiolXiUq_awaKVlghG6OMH_x2KO7NFdOvx4f-fzMSqZcu74_hXoLFVYphnYDtMjZPDobIkWfaFf_zKO4vav5pfCYcW5DzxfQw");
+
+        let verify = alg.verify(payload, &signature_bytes).unwrap();
+
+        assert!(verify);
+    }
+
+    #[test]
+    fn rs512_algorithm_can_be_loaded_from_pem() {
+        let payload = "hello world";
+        let alg = RS512Algorithm::from_pem(include_str!("../../../../test-files/rs256-pkcs8.key")).unwrap();
+
+        assert!(alg.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
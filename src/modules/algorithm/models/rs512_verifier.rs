@@ -0,0 +1,96 @@
+use std::fmt::{Debug, Formatter};
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::RsaPublicKey;
+use rsa::signature::Verifier;
+use sha2::Sha512;
+use crate::algorithm::{JwVerifier, KeyError};
+use crate::algorithm::formats;
+
+/// Verify-only counterpart of [RS512Algorithm](crate::algorithm::RS512Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct RS512Verifier {
+    inner: VerifyingKey<Sha512>,
+}
+
+impl RS512Verifier {
+    pub fn new(key: RsaPublicKey) -> Self {
+        RS512Verifier {
+            inner: VerifyingKey::new(key),
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not for this algorithm.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RS512Verifier::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RS512Verifier::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(AsRef::<RsaPublicKey>::as_ref(&self.inner))
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &VerifyingKey<Sha512> {
+        &self.inner
+    }
+}
+
+impl JwVerifier for RS512Verifier {
+    type Error = rsa::signature::Error;
+
+    fn alg(&self) -> &str {
+        "RS512"
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        let signature = Signature::try_from(signature)?;
+
+        Ok(self.inner.verify(payload.as_bytes(), &signature).is_ok())
+    }
+}
+
+impl Debug for RS512Verifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RS512Verifier {{ .. }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use rsa::pkcs8::DecodePublicKey;
+    use rsa::RsaPublicKey;
+    use crate::algorithm::{RS512Verifier, JwVerifier};
+
+    #[test]
+    fn rs512_verifier_works_as_expected() {
+        let payload = "eyJhbGciOiJSUzUxMiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let signature = "F71BK7fEdeL2W1ZZ9JJpE3WjXb8anq-JtLNoUnccQYBW3s72ONE2nXcNZuB6wojuAlnlm_hpkGrd-ToE7tbd3IR9Fej1mt2wDvsgCUSV_80KxOchDmpCKmF2PWvSuOG58tLCSquTUQjxX-zC72SkfzDcvm4KCJU7dBMphv8aCupZ8ExmAyn8Ya4qBnyIOewRiOq8UNIUNZFkgru3KJJoM1k7n9lD-xof3CKm_W_jK0dgDBn7Co46TLiolXiUq_awaKVlghG6OMH_x2KO7NFdOvx4f-fzMSqZcu74_hXoLFVYphnYDtMjZPDobIkWfaFf_zKO4vav5pfCYcW5DzxfQw";
+        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature).unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../../test-files/rs256.pub")).unwrap();
+        let verifier = RS512Verifier::new(public_key);
+
+        assert!(verifier.verify(payload, &signature_bytes).unwrap());
+        assert!(!verifier.verify("eyJhbGciOiJSUzUxMiIsInR5cCI6IkpXVCJ9.e30", &signature_bytes).unwrap());
+    }
+
+    #[test]
+    fn rs512_verifier_can_be_loaded_from_pem() {
+        use crate::algorithm::{JwSigner, RS512Algorithm};
+
+        let payload = "hello world";
+        let alg = RS512Algorithm::from_pem(include_str!("../../../../test-files/rs256.key")).unwrap();
+        let verifier = RS512Verifier::from_public_pem(include_str!("../../../../test-files/rs256-pkcs1.pub")).unwrap();
+
+        assert!(verifier.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
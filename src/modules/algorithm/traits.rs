@@ -1 +1,3 @@
-pub mod jw_alg;
\ No newline at end of file
+pub mod jw_alg;
+pub mod jw_signer;
+pub mod jw_verifier;
\ No newline at end of file
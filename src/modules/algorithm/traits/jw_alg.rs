@@ -1,7 +1,9 @@
-pub trait JwAlg {
-    type Error: std::error::Error;
+use crate::algorithm::{JwSigner, JwVerifier};
 
-    fn alg() -> impl AsRef<str>;
-    fn sign(&self, payload: &str) -> Vec<u8>;
-    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error>;
-}
\ No newline at end of file
+/// Shorthand for algorithms that can both sign and verify. Automatically implemented for every
+/// [JwSigner].
+pub trait JwAlg: JwSigner + JwVerifier {}
+
+impl<T> JwAlg for T
+where T : JwSigner + JwVerifier,
+{}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use crate::algorithm::JwVerifier;
+
+/// Creates signatures for a single algorithm. Anything that can sign can also verify its own
+/// signatures, which is why this requires [JwVerifier].
+pub trait JwSigner: JwVerifier {
+    fn sign(&self, payload: &str) -> Vec<u8>;
+
+    /// The ID of the key, which [Jwt::into_token](crate::token::Jwt::into_token) puts in the `kid`
+    /// header of the token. Most algorithms don't know their ID, but for example the active key of
+    /// a [KeyRing](crate::token::KeyRing) does.
+    fn kid(&self) -> Option<&str> {
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+/// Verifies signatures for a single algorithm. This is implemented by both the signing algorithms
+/// and the verify-only types that only hold a public key.
+pub trait JwVerifier {
+    type Error: std::error::Error;
+
+    fn alg(&self) -> &str;
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error>;
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use zeroize::Zeroizing;
+use crate::jwe::{JweDecrypter, JweEncrypter, JweError, JweHeader};
+
+/// Encrypts the plaintext using a random content encryption key and IV, and returns the JWE compact
+/// serialization from [RFC 7516 section 7.1](https://www.rfc-editor.org/rfc/rfc7516#section-7.1).
+pub(crate) fn encrypt<K: JweEncrypter + ?Sized>(
+    header: JweHeader,
+    plaintext: &[u8],
+    key: &K,
+) -> Result<String, JweError> {
+    let enc = header.content_encryption()?;
+
+    encrypt_with(header, plaintext, key, enc.generate_key(), &enc.generate_iv())
+}
+
+/// Same as [encrypt], but using the given content encryption key and IV.
+pub(crate) fn encrypt_with<K: JweEncrypter + ?Sized>(
+    mut header: JweHeader,
+    plaintext: &[u8],
+    key: &K,
+    cek: Zeroizing<Vec<u8>>,
+    iv: &[u8],
+) -> Result<String, JweError> {
+    let enc = header.content_encryption()?;
+    header.alg = Cow::Owned(key.alg().to_string());
+
+    let (cek, encrypted_key) = key.encrypt_key(&mut header, cek)?;
+
+    // The header is only serialized now, as the key management algorithm can add parameters.
+    let header_string = BASE64_URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+    let (ciphertext, tag) = enc.encrypt(&cek, iv, header_string.as_bytes(), plaintext)?;
+
+    Ok([
+        header_string,
+        BASE64_URL_SAFE_NO_PAD.encode(encrypted_key),
+        BASE64_URL_SAFE_NO_PAD.encode(iv),
+        BASE64_URL_SAFE_NO_PAD.encode(ciphertext),
+        BASE64_URL_SAFE_NO_PAD.encode(tag),
+    ].join("."))
+}
+
+/// Decrypts a JWE compact serialization, returning the header and the plaintext.
+pub(crate) fn decrypt<K: JweDecrypter + ?Sized>(
+    token: &str,
+    key: &K,
+) -> Result<(JweHeader<'static>, Zeroizing<Vec<u8>>), JweError> {
+    let parts = token.split('.').collect::<Vec<_>>();
+
+    let [header_string, encrypted_key, iv, ciphertext, tag] = parts.as_slice() else {
+        return Err(JweError::InvalidToken);
+    };
+
+    let header_bytes = BASE64_URL_SAFE_NO_PAD.decode(header_string.as_bytes())?;
+    let header: JweHeader<'static> = serde_json::from_slice(&header_bytes)?;
+
+    // None of the header extensions are supported, so these tokens must be rejected.
+    if header.crit.is_some() {
+        return Err(JweError::UnsupportedCrit);
+    }
+
+    if header.zip.is_some() {
+        return Err(JweError::UnsupportedZip);
+    }
+
+    if header.alg != key.alg() {
+        return Err(JweError::AlgMismatch);
+    }
+
+    let enc = header.content_encryption()?;
+
+    let encrypted_key = BASE64_URL_SAFE_NO_PAD.decode(encrypted_key.as_bytes())?;
+    let iv = BASE64_URL_SAFE_NO_PAD.decode(iv.as_bytes())?;
+    let ciphertext = BASE64_URL_SAFE_NO_PAD.decode(ciphertext.as_bytes())?;
+    let tag = BASE64_URL_SAFE_NO_PAD.decode(tag.as_bytes())?;
+
+    let cek = key.decrypt_key(&header, &encrypted_key)?;
+
+    if cek.len() != enc.key_len() {
+        return Err(JweError::DecryptionFailed);
+    }
+
+    let plaintext = enc.decrypt(&cek, &iv, header_string.as_bytes(), &ciphertext, &tag)?;
+
+    Ok((header, plaintext))
+}
\ No newline at end of file
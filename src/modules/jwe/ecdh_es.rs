@@ -0,0 +1,213 @@
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+use crate::jwe::{key_wrap, EcdhKey, EcdhPublicKey, JweError, JweHeader};
+use crate::jwk::Jwk;
+
+/// How the key derived using ECDH-ES is used, from
+/// [RFC 7518 section 4.6](https://www.rfc-editor.org/rfc/rfc7518#section-4.6).
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum KeyAgreement {
+    /// The derived key is used as the content encryption key (`ECDH-ES`).
+    Direct,
+
+    /// The derived key wraps the content encryption key using A128KW (`ECDH-ES+A128KW`).
+    A128KW,
+
+    /// The derived key wraps the content encryption key using A256KW (`ECDH-ES+A256KW`).
+    A256KW,
+}
+
+impl KeyAgreement {
+    /// Returns the AlgorithmID and the length of the key to derive. When the derived key is used
+    /// directly these come from the `enc`, otherwise from the `alg`.
+    fn derived_key(&self, header: &JweHeader) -> Result<(&'static str, usize), JweError> {
+        match self {
+            KeyAgreement::Direct => {
+                let enc = header.content_encryption()?;
+                Ok((enc.name(), enc.key_len()))
+            },
+            KeyAgreement::A128KW => Ok(("ECDH-ES+A128KW", 16)),
+            KeyAgreement::A256KW => Ok(("ECDH-ES+A256KW", 32)),
+        }
+    }
+}
+
+/// Generates an ephemeral key, sets it as the `epk` header parameter and derives the key agreed
+/// with the recipient. Either the derived key becomes the content encryption key, or it wraps the
+/// given content encryption key.
+pub(crate) fn encrypt_key(
+    agreement: KeyAgreement,
+    public_key: &EcdhPublicKey,
+    header: &mut JweHeader,
+    cek: Zeroizing<Vec<u8>>,
+) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+    let ephemeral_key = public_key.generate_ephemeral();
+
+    let shared_secret = ephemeral_key.diffie_hellman(public_key)
+        .ok_or(JweError::InvalidKey)?;
+
+    header.epk = Some(Jwk::from(&ephemeral_key.public_key()));
+    let derived_key = derive_key(agreement, header, &shared_secret)?;
+
+    match agreement {
+        KeyAgreement::Direct => Ok((derived_key, vec![])),
+        KeyAgreement::A128KW | KeyAgreement::A256KW => {
+            let encrypted_key = key_wrap::wrap(&derived_key, &cek)?;
+            Ok((cek, encrypted_key))
+        },
+    }
+}
+
+/// Derives the key agreed with the sender using the `epk` header parameter, and returns the
+/// content encryption key. Fails with [JweError::InvalidEpk] if the `epk` is missing or is not a
+/// valid point on the curve of the key.
+pub(crate) fn decrypt_key(
+    agreement: KeyAgreement,
+    key: &EcdhKey,
+    header: &JweHeader,
+    encrypted_key: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    let epk = header.epk.as_ref()
+        .ok_or(JweError::InvalidEpk)?;
+
+    let epk = EcdhPublicKey::try_from(epk)
+        .map_err(|_| JweError::InvalidEpk)?;
+
+    let shared_secret = key.diffie_hellman(&epk)
+        .ok_or(JweError::InvalidEpk)?;
+
+    let derived_key = derive_key(agreement, header, &shared_secret)?;
+
+    match agreement {
+        KeyAgreement::Direct if encrypted_key.is_empty() => Ok(derived_key),
+        KeyAgreement::Direct => Err(JweError::DecryptionFailed),
+        KeyAgreement::A128KW | KeyAgreement::A256KW => key_wrap::unwrap(&derived_key, encrypted_key),
+    }
+}
+
+fn derive_key(
+    agreement: KeyAgreement,
+    header: &JweHeader,
+    shared_secret: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    let (algorithm_id, key_len) = agreement.derived_key(header)?;
+
+    let apu = header.apu.as_ref()
+        .map(|apu| BASE64_URL_SAFE_NO_PAD.decode(apu.as_bytes()))
+        .transpose()?
+        .unwrap_or_default();
+
+    let apv = header.apv.as_ref()
+        .map(|apv| BASE64_URL_SAFE_NO_PAD.decode(apv.as_bytes()))
+        .transpose()?
+        .unwrap_or_default();
+
+    concat_kdf(shared_secret, algorithm_id, &apu, &apv, key_len)
+}
+
+/// The Concat KDF from [NIST SP 800-56A](https://doi.org/10.6028/NIST.SP.800-56Ar2) using SHA-256,
+/// with the OtherInfo described in
+/// [RFC 7518 section 4.6.2](https://www.rfc-editor.org/rfc/rfc7518#section-4.6.2).
+fn concat_kdf(
+    shared_secret: &[u8],
+    algorithm_id: &str,
+    apu: &[u8],
+    apv: &[u8],
+    key_len: usize,
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    let mut other_info = vec![];
+
+    // The AlgorithmID, PartyUInfo and PartyVInfo are all prefixed with their length.
+    for value in [algorithm_id.as_bytes(), apu, apv] {
+        let len = u32::try_from(value.len())
+            .map_err(|_| JweError::InvalidKey)?;
+
+        other_info.extend_from_slice(&len.to_be_bytes());
+        other_info.extend_from_slice(value);
+    }
+
+    // SuppPubInfo is the length of the derived key in bits.
+    other_info.extend_from_slice(&(key_len as u32 * 8).to_be_bytes());
+
+    let mut key = Zeroizing::new(vec![0; key_len]);
+    concat_kdf::derive_key_into::<Sha256>(shared_secret, &other_info, &mut key)
+        .map_err(|_| JweError::InvalidKey)?;
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use serde_json::json;
+    use x25519_dalek::StaticSecret;
+    use crate::jwe::{EcdhKey, EcdhPublicKey, JweHeader};
+    use crate::jwe::ecdh_es::{decrypt_key, KeyAgreement};
+    use crate::jwk::Jwk;
+
+    /// [RFC 7518 appendix C](https://www.rfc-editor.org/rfc/rfc7518#appendix-C).
+    #[test]
+    fn ecdh_es_matches_rfc_7518_appendix_c() {
+        let bob: Jwk = serde_json::from_value(json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "x": "weNJy2HscCSM6AEDTDg04biOvhFhyyWvOHQfeF_PxMQ",
+            "y": "e8lnCO-AlStT-NJVX-crhB7QRYhiix03illJOVAOyck",
+            "d": "VEmDZpDXXK8p8N0Cndsxs924q6nS1RXFASRl6BfUqdw"
+        })).unwrap();
+
+        let header: JweHeader = serde_json::from_value(json!({
+            "alg": "ECDH-ES",
+            "enc": "A128GCM",
+            "apu": "QWxpY2U",
+            "apv": "Qm9i",
+            "epk": {
+                "kty": "EC",
+                "crv": "P-256",
+                "x": "gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0",
+                "y": "SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps"
+            }
+        })).unwrap();
+
+        let bob = EcdhKey::try_from(&bob).unwrap();
+        let key = decrypt_key(KeyAgreement::Direct, &bob, &header, &[]).unwrap();
+
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(&key), "VqqN6vgjbSBcIijNcacQGg");
+    }
+
+    /// [RFC 7748 section 6.1](https://www.rfc-editor.org/rfc/rfc7748#section-6.1), using the keys
+    /// from [RFC 8037 appendix A.6](https://www.rfc-editor.org/rfc/rfc8037#appendix-A.6).
+    #[test]
+    fn x25519_matches_rfc_7748() {
+        let alice = EcdhKey::X25519(StaticSecret::from([
+            0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2, 0x66, 0x45,
+            0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5, 0x1d, 0xb9, 0x2c, 0x2a,
+        ]));
+
+        let bob = EcdhKey::X25519(StaticSecret::from([
+            0x5d, 0xab, 0x08, 0x7e, 0x62, 0x4a, 0x8a, 0x4b, 0x79, 0xe1, 0x7f, 0x8b, 0x83, 0x80, 0x0e, 0xe6,
+            0x6f, 0x3b, 0xb1, 0x29, 0x26, 0x18, 0xb6, 0xfd, 0x1c, 0x2f, 0x8b, 0x27, 0xff, 0x88, 0xe0, 0xeb,
+        ]));
+
+        let bob_jwk = Jwk::from(&bob.public_key());
+        assert_eq!(bob_jwk, serde_json::from_value(json!({
+            "kty": "OKP",
+            "crv": "X25519",
+            "x": "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"
+        })).unwrap());
+
+        let bob_public_key = EcdhPublicKey::try_from(&bob_jwk).unwrap();
+        let shared_secret = alice.diffie_hellman(&bob_public_key).unwrap();
+
+        assert_eq!(shared_secret.as_slice(), [
+            0x4a, 0x5d, 0x9d, 0x5b, 0xa4, 0xce, 0x2d, 0xe1, 0x72, 0x8e, 0x3b, 0xf4, 0x80, 0x35, 0x0f, 0x25,
+            0xe0, 0x7e, 0x21, 0xc9, 0x47, 0xd1, 0x9e, 0x33, 0x76, 0xf0, 0x9b, 0x3c, 0x1e, 0x16, 0x17, 0x42,
+        ]);
+
+        // A low order point results in a shared secret of all zeros, which is rejected.
+        assert!(alice.diffie_hellman(&EcdhPublicKey::X25519([0; 32].into())).is_none());
+    }
+}
\ No newline at end of file
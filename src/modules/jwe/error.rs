@@ -0,0 +1,38 @@
+use thiserror::Error;
+use crate::token::JwtError;
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub enum JweError {
+    SerdeJson(#[from] serde_json::Error),
+    DecodeError(#[from] base64::DecodeError),
+
+    /// Returned when the claims of the token are not valid.
+    JwtError(#[from] JwtError),
+
+    #[error("A JWE token must consist of five parts")]
+    InvalidToken,
+
+    #[error("JWE token does not specify the correct `alg` in the header")]
+    AlgMismatch,
+
+    #[error("The `enc` `{0}` is not supported")]
+    UnsupportedEnc(String),
+
+    #[error("The JWE token uses critical header parameters that are not supported")]
+    UnsupportedCrit,
+
+    #[error("Compressed JWE tokens are not supported")]
+    UnsupportedZip,
+
+    #[error("The key cannot be used with the `enc` of the JWE token")]
+    InvalidKey,
+
+    #[error("The `epk` of the JWE token is missing or not a valid public key for the curve of the key")]
+    InvalidEpk,
+
+    /// Returned for every failure while decrypting the key or the content, so it doesn't reveal
+    /// which of the steps failed.
+    #[error("The JWE token could not be decrypted")]
+    DecryptionFailed,
+}
\ No newline at end of file
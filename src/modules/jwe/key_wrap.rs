@@ -0,0 +1,70 @@
+use aes_kw::{KekAes128, KekAes256, IV_LEN};
+use zeroize::Zeroizing;
+use crate::jwe::JweError;
+
+/// Wraps the content encryption key using AES Key Wrap from
+/// [RFC 3394](https://www.rfc-editor.org/rfc/rfc3394), using AES-128 or AES-256 depending on the
+/// size of the key encryption key.
+pub(crate) fn wrap(kek: &[u8], cek: &[u8]) -> Result<Vec<u8>, JweError> {
+    let mut wrapped = vec![0; cek.len() + IV_LEN];
+
+    let result = match kek.len() {
+        16 => KekAes128::try_from(kek).and_then(|kek| kek.wrap(cek, &mut wrapped)),
+        32 => KekAes256::try_from(kek).and_then(|kek| kek.wrap(cek, &mut wrapped)),
+        _ => return Err(JweError::InvalidKey),
+    };
+
+    result.map_err(|_| JweError::InvalidKey)?;
+    Ok(wrapped)
+}
+
+/// Unwraps a key wrapped by [wrap]. Fails with [JweError::DecryptionFailed] if the integrity check
+/// fails.
+pub(crate) fn unwrap(kek: &[u8], wrapped: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    if wrapped.len() < IV_LEN {
+        return Err(JweError::DecryptionFailed);
+    }
+
+    let mut cek = Zeroizing::new(vec![0; wrapped.len() - IV_LEN]);
+
+    let result = match kek.len() {
+        16 => KekAes128::try_from(kek).and_then(|kek| kek.unwrap(wrapped, &mut cek)),
+        32 => KekAes256::try_from(kek).and_then(|kek| kek.unwrap(wrapped, &mut cek)),
+        _ => return Err(JweError::DecryptionFailed),
+    };
+
+    result.map_err(|_| JweError::DecryptionFailed)?;
+    Ok(cek)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jwe::key_wrap;
+
+    /// [RFC 3394 section 4.6](https://www.rfc-editor.org/rfc/rfc3394#section-4.6): wrap 256 bits of
+    /// key data with a 256-bit KEK.
+    #[test]
+    fn aes_256_key_wrap_matches_rfc_3394() {
+        let kek = (0..32).collect::<Vec<u8>>();
+        let key_data = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ];
+
+        let wrapped = key_wrap::wrap(&kek, &key_data).unwrap();
+
+        assert_eq!(wrapped, [
+            0x28, 0xc9, 0xf4, 0x04, 0xc4, 0xb8, 0x10, 0xf4, 0xcb, 0xcc, 0xb3, 0x5c, 0xfb, 0x87, 0xf8, 0x26,
+            0x3f, 0x57, 0x86, 0xe2, 0xd8, 0x0e, 0xd3, 0x26, 0xcb, 0xc7, 0xf0, 0xe7, 0x1a, 0x99, 0xf4, 0x3b,
+            0xfb, 0x98, 0x8b, 0x9b, 0x7a, 0x02, 0xdd, 0x21,
+        ]);
+
+        assert_eq!(key_wrap::unwrap(&kek, &wrapped).unwrap().as_slice(), key_data);
+
+        let mut tampered = wrapped.clone();
+        tampered[8] ^= 1;
+
+        assert!(key_wrap::unwrap(&kek, &tampered).is_err());
+        assert!(key_wrap::unwrap(&kek, &wrapped[..7]).is_err());
+    }
+}
\ No newline at end of file
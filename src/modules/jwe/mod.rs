@@ -0,0 +1,510 @@
+mod models;
+mod traits;
+mod error;
+mod compact;
+mod key_wrap;
+
+#[cfg(any(feature = "rsa-oaep", feature = "rsa-oaep-256"))]
+mod rsa_oaep;
+
+#[cfg(feature = "ecdh-es")]
+mod ecdh_es;
+
+pub use models::jwe::Jwe;
+pub use models::jwe_header::JweHeader;
+pub use models::content_encryption::ContentEncryption;
+pub use models::dir_algorithm::DirAlgorithm;
+pub use models::a128kw_algorithm::A128KWAlgorithm;
+pub use models::a256kw_algorithm::A256KWAlgorithm;
+pub use models::a256gcmkw_algorithm::A256GCMKWAlgorithm;
+pub use traits::jwe_encrypter::JweEncrypter;
+pub use traits::jwe_decrypter::JweDecrypter;
+pub use error::JweError;
+
+#[cfg(feature = "rsa-oaep")]
+pub use models::rsa_oaep_algorithm::RSAOAEPAlgorithm;
+
+#[cfg(feature = "rsa-oaep")]
+pub use models::rsa_oaep_encrypter::RSAOAEPEncrypter;
+
+#[cfg(feature = "rsa-oaep-256")]
+pub use models::rsa_oaep_256_algorithm::RSAOAEP256Algorithm;
+
+#[cfg(feature = "rsa-oaep-256")]
+pub use models::rsa_oaep_256_encrypter::RSAOAEP256Encrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_key::EcdhKey;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_public_key::EcdhPublicKey;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_algorithm::ECDHESAlgorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_encrypter::ECDHESEncrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_a128kw_algorithm::ECDHESA128KWAlgorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_a128kw_encrypter::ECDHESA128KWEncrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_a256kw_algorithm::ECDHESA256KWAlgorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub use models::ecdh_es_a256kw_encrypter::ECDHESA256KWEncrypter;
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use serde_json::{json, Value};
+    use crate::jwe::{compact, A128KWAlgorithm, A256GCMKWAlgorithm, A256KWAlgorithm, ContentEncryption, DirAlgorithm, Jwe, JweDecrypter, JweEncrypter, JweError, JweHeader};
+    use crate::jwk::Jwk;
+    use crate::token::{JwtClaims, JwtError};
+
+    /// The plaintext used by the examples in
+    /// [RFC 7520 section 5](https://www.rfc-editor.org/rfc/rfc7520#section-5).
+    pub(crate) const RFC_7520_PLAINTEXT: &str = "You can trust us to stick with you through thick and thin\u{2013}to the bitter end. And you can trust us to keep any secret of yours\u{2013}closer than you keep it yourself. But you cannot trust us to let you face trouble alone, and go off without a word. We are your friends, Frodo.";
+
+    /// [RFC 7520 section 5.6](https://www.rfc-editor.org/rfc/rfc7520#section-5.6): direct
+    /// encryption using AES-GCM.
+    #[test]
+    fn dir_with_a128gcm_decrypts_rfc_7520_example() {
+        let jwk: Jwk = serde_json::from_value(json!({
+            "kty": "oct",
+            "kid": "77c7e2b8-6e13-45cf-8672-617b5b45243a",
+            "use": "enc",
+            "alg": "A128GCM",
+            "k": "XctOhJAkA-pD9Lh7ZgW_2A"
+        })).unwrap();
+
+        let key = DirAlgorithm::new(&jwk.secret().unwrap());
+
+        let token = concat!(
+            "eyJhbGciOiJkaXIiLCJraWQiOiI3N2M3ZTJiOC02ZTEzLTQ1Y2YtODY3Mi02MTdiNWI0NTI0M2EiLCJlbmMiOiJBMTI4R0NNIn0",
+            "..refa467QzzKx6QAB.",
+            "JW_i_f52hww_ELQPGaYyeAB6HYGcR559l9TYnSovc23XJoBcW29rHP8yZOZG7YhLpT1bjFuvZPjQS-m0IFtVcXkZXdH_lr_FrdY",
+            "t9HRUYkshtrMmIUAyGmUnd9zMDB2n0cRDIHAzFVeJUDxkUwVAE7_YGRPdcqMyiBoCO-FBdE-Nceb4h3-FtBP-c_BIwCPTjb9o0S",
+            "bdcdREEMJMyZBH8ySWMVi1gPD9yxi-aQpGbSv_F9N4IZAxscj5g-NJsUPbjk29-s7LJAGb15wEBtXphVCgyy53CoIKLHHeJHXex",
+            "45Uz9aKZSRSInZI-wjsY0yu3cT4_aQ3i1o-tiE-F8Ios61EKgyIQ4CWao8PFMj8TTnp",
+            ".vbb32Xvllea2OtmHAdccRQ",
+        );
+
+        let (header, plaintext) = compact::decrypt(token, &key).unwrap();
+
+        assert_eq!(header.enc, "A128GCM");
+        assert_eq!(header.kid.as_deref(), Some("77c7e2b8-6e13-45cf-8672-617b5b45243a"));
+        assert_eq!(plaintext.as_slice(), RFC_7520_PLAINTEXT.as_bytes());
+
+        // Encrypting with the same IV produces the same ciphertext, apart from the header which is
+        // part of the additional authenticated data.
+        let header = JweHeader::default()
+            .with_enc(ContentEncryption::A128GCM)
+            .with_kid("77c7e2b8-6e13-45cf-8672-617b5b45243a");
+
+        let iv = BASE64_URL_SAFE_NO_PAD.decode("refa467QzzKx6QAB").unwrap();
+        let encrypted = compact::encrypt_with(header, RFC_7520_PLAINTEXT.as_bytes(), &key, Default::default(), &iv).unwrap();
+
+        let (_, plaintext) = compact::decrypt(&encrypted, &key).unwrap();
+        assert_eq!(plaintext.as_slice(), RFC_7520_PLAINTEXT.as_bytes());
+        assert_eq!(encrypted.split('.').nth(3), token.split('.').nth(3));
+    }
+
+    #[test]
+    fn jwe_can_be_encrypted_and_checked() {
+        let encs = [
+            ContentEncryption::A128GCM,
+            ContentEncryption::A256GCM,
+            ContentEncryption::A128CBCHS256,
+            ContentEncryption::A256CBCHS512,
+        ];
+
+        for enc in encs {
+            let key = DirAlgorithm::generate(enc);
+
+            let token = Jwe::new(json!({ "email": "frodo@example.com" }))
+                .with_enc(enc)
+                .with_claims(JwtClaims::default().issuer("shire").not_before_seconds(0).expire_in_seconds(60))
+                .into_token(&key)
+                .unwrap();
+
+            assert_eq!(token.split('.').nth(1), Some(""));
+
+            let jwe = Jwe::<Value>::verify_now(&token, &key)
+                .unwrap()
+                .against(&JwtClaims::default().issuer("shire"))
+                .unwrap();
+
+            assert_eq!(jwe.header().alg, "dir");
+            assert_eq!(jwe.header().enc, enc.name());
+            assert_eq!(jwe.payload()["email"], "frodo@example.com");
+
+            let other_key = DirAlgorithm::generate(enc);
+            assert!(matches!(Jwe::<Value>::check(&token, &other_key), Err(JweError::DecryptionFailed)));
+        }
+    }
+
+    #[test]
+    fn jwe_claims_are_validated() {
+        let key = DirAlgorithm::generate(ContentEncryption::A256GCM);
+
+        let token = Jwe::new(json!({}))
+            .with_claims(JwtClaims::default().not_before_seconds(0).expire_in_seconds(-60))
+            .into_token(&key)
+            .unwrap();
+
+        let jwe = Jwe::<Value>::verify_now(&token, &key);
+        assert!(matches!(jwe, Err(JweError::JwtError(JwtError::MismatchedExpClaim))));
+    }
+
+    #[test]
+    fn tampered_jwe_is_rejected() {
+        let key = DirAlgorithm::generate(ContentEncryption::A256GCM);
+
+        let token = Jwe::new(json!({ "hello": "world" }))
+            .into_token(&key)
+            .unwrap();
+
+        let mut parts = token.split('.').map(str::to_string).collect::<Vec<_>>();
+
+        // Changing the protected header changes the additional authenticated data.
+        let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"dir","enc":"A256GCM","typ":"JOSE"}"#);
+        let tampered = [header.as_str(), &parts[1], &parts[2], &parts[3], &parts[4]].join(".");
+        assert!(matches!(Jwe::<Value>::check(&tampered, &key), Err(JweError::DecryptionFailed)));
+
+        parts[3] = BASE64_URL_SAFE_NO_PAD.encode(b"something else");
+        assert!(matches!(Jwe::<Value>::check(&parts.join("."), &key), Err(JweError::DecryptionFailed)));
+
+        assert!(matches!(Jwe::<Value>::check(&parts[..4].join("."), &key), Err(JweError::InvalidToken)));
+    }
+
+    #[test]
+    fn dir_key_must_match_the_enc() {
+        let key = DirAlgorithm::generate(ContentEncryption::A128GCM);
+
+        let result = Jwe::new(json!({}))
+            .with_enc(ContentEncryption::A256GCM)
+            .into_token(&key);
+
+        assert!(matches!(result, Err(JweError::InvalidKey)));
+
+        let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"dir","enc":"A128GCM","zip":"DEF"}"#);
+        let token = format!("{}..AAAAAAAAAAAAAAAA.AA.AAAAAAAAAAAAAAAAAAAAAA", header);
+        assert!(matches!(Jwe::<Value>::check(&token, &key), Err(JweError::UnsupportedZip)));
+
+        let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"dir","enc":"A192GCM"}"#);
+        let token = format!("{}..AAAAAAAAAAAAAAAA.AA.AAAAAAAAAAAAAAAAAAAAAA", header);
+        assert!(matches!(Jwe::<Value>::check(&token, &key), Err(JweError::UnsupportedEnc(_))));
+    }
+
+    /// [RFC 7520 section 5.8](https://www.rfc-editor.org/rfc/rfc7520#section-5.8): key wrap using
+    /// AES-KeyWrap with AES-GCM.
+    #[test]
+    fn a128kw_decrypts_rfc_7520_example() {
+        let jwk: Jwk = serde_json::from_value(json!({
+            "kty": "oct",
+            "kid": "81b20965-8332-43d9-a468-82160ad91ac8",
+            "use": "enc",
+            "alg": "A128KW",
+            "k": "GZy6sIZ6wl9NJOKB-jnmVQ"
+        })).unwrap();
+
+        let key = A128KWAlgorithm::new(&jwk.secret().unwrap()).unwrap();
+
+        let token = concat!(
+            "eyJhbGciOiJBMTI4S1ciLCJraWQiOiI4MWIyMDk2NS04MzMyLTQzZDktYTQ2OC04MjE2MGFkOTFhYzgiLCJlbmMiOiJBMTI4R0NNIn0",
+            ".CBI6oDw8MydIx1IBntf_lQcw2MmJKIQx",
+            ".Qx0pmsDa8KnJc9Jo.",
+            "AwliP-KmWgsZ37BvzCefNen6VTbRK3QMA4TkvRkH0tP1bTdhtFJgJxeVmJkLD61A1hnWGetdg11c9ADsnWgL56NyxwSYjU1ZEHcG",
+            "kd3EkU0vjHi9gTlb90qSYFfeF0LwkcTtjbYKCsiNJQkcIp1yeM03OmuiYSoYJVSpf7ej6zaYcMv3WwdxDFl8REwOhNImk2Xld2JXq6B",
+            "R53TSFkyT7PwVLuq-1GwtGHlQeg7gDT6xW0JqHDPn_H-puQsmthc9Zg0ojmJfqqFvETUxLAF-KjcBTS5dNy6egwkYtOt8EIHK-oEsKY",
+            "tZRaa8Z7MOZ7UGxGIMvEmxrGCPeJa14slv2-gaqK0kEThkaSqdYw0FkQZF",
+            ".ER7MWJZ1FBI_NKvn7Zb1Lw",
+        );
+
+        let (header, plaintext) = compact::decrypt(token, &key).unwrap();
+
+        assert_eq!(header.alg, "A128KW");
+        assert_eq!(plaintext.as_slice(), RFC_7520_PLAINTEXT.as_bytes());
+
+        // Wrapping the content encryption key from the example results in the same encrypted key.
+        let cek = BASE64_URL_SAFE_NO_PAD.decode("aY5_Ghmk9KxWPBLu_glx1w").unwrap();
+        let (_, encrypted_key) = key.encrypt_key(&mut JweHeader::default(), cek.into()).unwrap();
+
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(encrypted_key), "CBI6oDw8MydIx1IBntf_lQcw2MmJKIQx");
+    }
+
+    /// [RFC 7520 section 5.7](https://www.rfc-editor.org/rfc/rfc7520#section-5.7): key wrap using
+    /// AES-GCM KeyWrap with AES-CBC-HMAC-SHA2.
+    #[test]
+    fn a256gcmkw_decrypts_rfc_7520_example() {
+        let jwk: Jwk = serde_json::from_value(json!({
+            "kty": "oct",
+            "kid": "18ec08e1-bfa9-4d95-b205-2b4dd1d4321d",
+            "use": "enc",
+            "alg": "A256GCMKW",
+            "k": "qC57l_uxcm7Nm3K-ct4GFjx8tM1U8CZ0NLBvdQstiS8"
+        })).unwrap();
+
+        let key = A256GCMKWAlgorithm::new(&jwk.secret().unwrap()).unwrap();
+
+        let header_string = concat!(
+            "eyJhbGciOiJBMjU2R0NNS1ciLCJraWQiOiIxOGVjMDhlMS1iZmE5LTRkOTUtYjIwNS0yYjRkZDFkNDMyMWQiLCJ0YWciOiJrZlB",
+            "kdVZRM1QzSDZ2bmV3dC0ta3N3IiwiaXYiOiJLa1lUMEdYXzJqSGxmcU5fIiwiZW5jIjoiQTEyOENCQy1IUzI1NiJ9",
+        );
+
+        let token = [
+            header_string,
+            ".lJf3HbOApxMEBkCMOoTnnABxs_CvTWUmZQ2ElLvYNok",
+            ".gz6NjyEFNm_vm8Gj6FwoFQ",
+            ".Jf5p9-ZhJlJy_IQ_byKFmI0Ro7w7G1QiaZpI8OaiVgD8EqoDZHyFKFBupS8iaEeVIgMqWmsuJKuoVgzR3YfzoMd3GxEm3VxNhzWy",
+            "WtZKX0gxKdy6HgLvqoGNbZCzLjqcpDiF8q2_62EVAbr2uSc2oaxFmFuIQHLcqAHxy51449xkjZ7ewzZaGV3eFqhpco8o4DijXaG5",
+            "_7kp3h2cajRfDgymuxUbWgLqaeNQaJtvJmSMFuEOSAzw9Hdeb6yhdTynCRmu-kqtO5Dec4lT2OMZKpnxc_F1_4yDJFcqb5CiDSmA",
+            "-psB2k0JtjxAj4UPI61oONK7zzFIu4gBfjJCndsZfdvG7h8wGjV98QhrKEnR7xKZ3KCr0_qR1B-gxpNk3xWU",
+            ".DKW7jrb4WaRSNfbXVPlT5g",
+        ].concat();
+
+        let (header, plaintext) = compact::decrypt(&token, &key).unwrap();
+
+        assert_eq!(header.content_encryption().unwrap(), ContentEncryption::A128CBCHS256);
+        assert_eq!(plaintext.as_slice(), RFC_7520_PLAINTEXT.as_bytes());
+
+        let mut header: JweHeader = serde_json::from_slice(&BASE64_URL_SAFE_NO_PAD.decode(header_string).unwrap()).unwrap();
+        let encrypted_key = BASE64_URL_SAFE_NO_PAD.decode("lJf3HbOApxMEBkCMOoTnnABxs_CvTWUmZQ2ElLvYNok").unwrap();
+
+        let cek = key.decrypt_key(&header, &encrypted_key).unwrap();
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(&cek), "UWxARpat23nL9ReIj4WG3D1ee9I4r-Mv5QLuFXdy_rE");
+
+        // Both the IV and the tag are needed to decrypt the key.
+        header.tag = Some("AAAAAAAAAAAAAAAAAAAAAA".into());
+        assert!(matches!(key.decrypt_key(&header, &encrypted_key), Err(JweError::DecryptionFailed)));
+
+        header.tag = None;
+        assert!(matches!(key.decrypt_key(&header, &encrypted_key), Err(JweError::DecryptionFailed)));
+    }
+
+    #[test]
+    fn key_wrapping_algorithms_round_trip() {
+        fn round_trip<K: JweEncrypter + JweDecrypter>(key: &K, other: &K) {
+            let encs = [
+            ContentEncryption::A128GCM,
+            ContentEncryption::A256GCM,
+            ContentEncryption::A128CBCHS256,
+            ContentEncryption::A256CBCHS512,
+        ];
+
+        for enc in encs {
+                let token = Jwe::new(json!({ "email": "frodo@example.com" }))
+                    .with_enc(enc)
+                    .into_token(key)
+                    .unwrap();
+
+                let jwe = Jwe::<Value>::check(&token, key)
+                    .unwrap();
+
+                assert_eq!(jwe.header().alg, JweEncrypter::alg(key));
+                assert_eq!(jwe.payload()["email"], "frodo@example.com");
+
+                assert!(matches!(Jwe::<Value>::check(&token, other), Err(JweError::DecryptionFailed)));
+            }
+        }
+
+        round_trip(&A128KWAlgorithm::generate(), &A128KWAlgorithm::generate());
+        round_trip(&A256KWAlgorithm::generate(), &A256KWAlgorithm::generate());
+        round_trip(&A256GCMKWAlgorithm::generate(), &A256GCMKWAlgorithm::generate());
+
+        assert!(matches!(A128KWAlgorithm::new(&[0; 32]), Err(JweError::InvalidKey)));
+        assert!(matches!(A256KWAlgorithm::new(&[0; 16]), Err(JweError::InvalidKey)));
+
+        // A token for one algorithm cannot be decrypted by another.
+        let token = Jwe::new(json!({}))
+            .into_token(&A128KWAlgorithm::generate())
+            .unwrap();
+
+        assert!(matches!(Jwe::<Value>::check(&token, &A256KWAlgorithm::generate()), Err(JweError::AlgMismatch)));
+    }
+
+    /// Decrypts an external token for `test-files/rs256.key`, round trips a token using only the
+    /// public key and checks that a tampered encrypted key fails in the same way as a tampered
+    /// ciphertext.
+    #[cfg(any(feature = "rsa-oaep", feature = "rsa-oaep-256"))]
+    fn rsa_oaep_works_as_expected<K, E>(token: &str, key: &K, encrypter: &E)
+    where
+        K: JweEncrypter + JweDecrypter,
+        E: JweEncrypter,
+    {
+        let (header, plaintext) = compact::decrypt(token, key).unwrap();
+
+        assert_eq!(header.alg, JweDecrypter::alg(key));
+        assert_eq!(plaintext.as_slice(), br#"{"email":"frodo@example.com"}"#);
+
+        let token = Jwe::new(json!({ "email": "frodo@example.com" }))
+            .into_token(encrypter)
+            .unwrap();
+
+        let jwe = Jwe::<Value>::check(&token, key).unwrap();
+        assert_eq!(jwe.payload()["email"], "frodo@example.com");
+
+        let mut parts = token.split('.').map(String::from).collect::<Vec<_>>();
+        let mut encrypted_key = BASE64_URL_SAFE_NO_PAD.decode(&parts[1]).unwrap();
+        encrypted_key[0] ^= 1;
+        parts[1] = BASE64_URL_SAFE_NO_PAD.encode(&encrypted_key);
+
+        assert!(matches!(Jwe::<Value>::check(&parts.join("."), key), Err(JweError::DecryptionFailed)));
+
+        // Instead of an error, a random key is returned when the encrypted key cannot be decrypted.
+        let header = JweHeader::default();
+        let first = key.decrypt_key(&header, &encrypted_key).unwrap();
+        let second = key.decrypt_key(&header, &encrypted_key).unwrap();
+
+        assert_eq!(first.len(), 32);
+        assert_ne!(first, second);
+    }
+
+    /// Token created with Python's `cryptography` using OAEP with SHA-1 for `test-files/rs256.pub`.
+    #[test]
+    #[cfg(feature = "rsa-oaep")]
+    fn rsa_oaep_algorithm_works_as_expected() {
+        use crate::jwe::{RSAOAEPAlgorithm, RSAOAEPEncrypter};
+
+        let token = concat!(
+            "eyJhbGciOiJSU0EtT0FFUCIsImVuYyI6IkEyNTZHQ00ifQ",
+            ".jHsSud_u8IMPlwSLWB6-IbtvogGruKlAbSBMejZfIqa8rWmCsfsl1y8lE6aKruvckLiDec_JzJS6HY01A_ep6B4MEcagoUJ-6Q1",
+            "UVOyDIUxXoNYKI0i1nBiAT3t9hll9mt66HrtqgzSLdZTS9BKRdlCbZ_T8X868f0YPBCvtmxzIIpUCWIGyzqHIqOXYm6eQRYndv7MB",
+            "MdvQg-FzJpRl8BRrsNbTDig6ERzOsfyWOUF3ftWE-IV2tDw7kOC1OST9VgteY5ld-fRQF41cYZ4ur_0hK2KHTYzQdB0wo43T9Tokc",
+            "YkaafOJH8YlCP9EAcBgdJ9An6xJJ_5YuY9EU0x5Yg",
+            ".6YVFLzHIxdnChtUP",
+            ".PBpe8UpC8YCOfdr8RP-fEUXPSGbKyVo9xyVsRJQ",
+            ".AFczZweUh0L4yeoexcZQgQ",
+        );
+
+        let key = RSAOAEPAlgorithm::from_pem(include_str!("../../../test-files/rs256.key")).unwrap();
+        let encrypter = RSAOAEPEncrypter::from_public_pem(include_str!("../../../test-files/rs256.pub")).unwrap();
+
+        rsa_oaep_works_as_expected(token, &key, &encrypter);
+
+        let result = RSAOAEPAlgorithm::from_pem(include_str!("../../../test-files/rs1024.key"));
+        assert!(matches!(result, Err(crate::algorithm::KeyError::KeyTooSmall(1024))));
+    }
+
+    /// Token created with Python's `cryptography` using OAEP with SHA-256 for
+    /// `test-files/rs256.pub`.
+    #[test]
+    #[cfg(feature = "rsa-oaep-256")]
+    fn rsa_oaep_256_algorithm_works_as_expected() {
+        use crate::jwe::{RSAOAEP256Algorithm, RSAOAEP256Encrypter};
+
+        let token = concat!(
+            "eyJhbGciOiJSU0EtT0FFUC0yNTYiLCJlbmMiOiJBMjU2R0NNIn0",
+            ".D_HO0xrhlk6nz69mhR22R1BsvKxwhxQq56Rgruff9r8mceDW0P7aF-XiZNvix7N4kk3i3p2J78EbD85Vc8MtaF7ddpF4ohHtgbd",
+            "YTr1NIs49TQqn3hgB7km6AjFEI3an5Vjl5yCZVn1rkmB2LDe6FrW-Blg-42Yq9efkT9U55bhZiAoHyYCZx1AkPTXZEGJkxpAPUpui",
+            "vgcN2k596QfOJ6Tp8TDdr_aMMwXEjGVZLmEiwYX7gsfBeZNdpQvFy4-OSYdSUcRxpjWqaSyShrcXigaspevUr44YwzrYdmTKI05vk",
+            "UYlzaQfyMQVSzUeq25MMTYA0X-XvIHgDt53kSMyPg",
+            ".Jl9EyT530oq-hsus",
+            ".5jgTv9AxVOjBzg1cgi1StoQp1EsuOQrXCO5EGX4",
+            ".0eHGrNcSaxZ7rzVhlSqbRg",
+        );
+
+        let key = RSAOAEP256Algorithm::from_pem(include_str!("../../../test-files/rs256.key")).unwrap();
+        let encrypter = RSAOAEP256Encrypter::from_public_pem(include_str!("../../../test-files/rs256.pub")).unwrap();
+
+        rsa_oaep_works_as_expected(token, &key, &encrypter);
+
+        // The same key can be used for both variants, but the tokens are not interchangeable.
+        #[cfg(feature = "rsa-oaep")]
+        {
+            let token = Jwe::new(json!({}))
+                .into_token(&encrypter)
+                .unwrap();
+
+            let key = crate::jwe::RSAOAEPAlgorithm::new(key.key().clone());
+            assert!(matches!(Jwe::<Value>::check(&token, &key), Err(JweError::AlgMismatch)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ecdh-es")]
+    fn ecdh_es_algorithms_round_trip() {
+        use crate::jwe::{ECDHESA128KWAlgorithm, ECDHESA256KWAlgorithm, ECDHESAlgorithm, EcdhKey};
+
+        fn round_trip<K, E>(key: &K, encrypter: &E, other: &K)
+        where
+            K: JweDecrypter,
+            E: JweEncrypter,
+        {
+            let header = JweHeader::default()
+                .with_enc(ContentEncryption::A128GCM)
+                .with_apu("Alice")
+                .with_apv("Bob");
+
+            let token = Jwe::new(json!({ "email": "frodo@example.com" }))
+                .with_header(header)
+                .into_token(encrypter)
+                .unwrap();
+
+            let jwe = Jwe::<Value>::check(&token, key).unwrap();
+
+            assert_eq!(jwe.header().alg, encrypter.alg());
+            assert_eq!(jwe.header().apu.as_deref(), Some("QWxpY2U"));
+            assert!(jwe.header().epk.is_some());
+            assert_eq!(jwe.payload()["email"], "frodo@example.com");
+
+            assert!(matches!(Jwe::<Value>::check(&token, other), Err(JweError::DecryptionFailed)));
+        }
+
+        for generate in [EcdhKey::generate_p256, EcdhKey::generate_x25519] {
+            let key = ECDHESAlgorithm::new(generate());
+            round_trip(&key, &key.encrypter(), &ECDHESAlgorithm::new(generate()));
+
+            let key = ECDHESA128KWAlgorithm::new(generate());
+            round_trip(&key, &key.encrypter(), &ECDHESA128KWAlgorithm::new(generate()));
+
+            let key = ECDHESA256KWAlgorithm::new(generate());
+            round_trip(&key, &key.encrypter(), &ECDHESA256KWAlgorithm::new(generate()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ecdh-es")]
+    fn ecdh_es_rejects_invalid_ephemeral_keys() {
+        use crate::jwe::{ECDHESAlgorithm, EcdhKey};
+
+        let key = ECDHESAlgorithm::new(EcdhKey::generate_p256());
+
+        let token = Jwe::new(json!({}))
+            .into_token(&key)
+            .unwrap();
+
+        let parts = token.split('.').collect::<Vec<_>>();
+        let header_bytes = BASE64_URL_SAFE_NO_PAD.decode(parts[0]).unwrap();
+        let header: Value = serde_json::from_slice(&header_bytes).unwrap();
+
+        let with_epk = |epk: Value| {
+            let mut header = header.clone();
+            header["epk"] = epk;
+
+            let header_string = BASE64_URL_SAFE_NO_PAD.encode(header.to_string());
+            [header_string.as_str(), parts[1], parts[2], parts[3], parts[4]].join(".")
+        };
+
+        // A point that is not on the curve, as used in invalid curve attacks.
+        let mut epk = header["epk"].clone();
+        epk["y"] = json!(BASE64_URL_SAFE_NO_PAD.encode([1; 32]));
+
+        let result = Jwe::<Value>::check(&with_epk(epk), &key);
+        assert!(matches!(result, Err(JweError::InvalidEpk)));
+
+        // An ephemeral key on another curve.
+        let epk = Jwk::from(&EcdhKey::generate_x25519().public_key());
+
+        let result = Jwe::<Value>::check(&with_epk(serde_json::to_value(epk).unwrap()), &key);
+        assert!(matches!(result, Err(JweError::InvalidEpk)));
+
+        let result = Jwe::<Value>::check(&with_epk(Value::Null), &key);
+        assert!(matches!(result, Err(JweError::InvalidEpk)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+pub mod jwe;
+pub mod jwe_header;
+pub mod content_encryption;
+pub mod dir_algorithm;
+pub mod a128kw_algorithm;
+pub mod a256kw_algorithm;
+pub mod a256gcmkw_algorithm;
+
+#[cfg(feature = "rsa-oaep")]
+pub mod rsa_oaep_algorithm;
+
+#[cfg(feature = "rsa-oaep")]
+pub mod rsa_oaep_encrypter;
+
+#[cfg(feature = "rsa-oaep-256")]
+pub mod rsa_oaep_256_algorithm;
+
+#[cfg(feature = "rsa-oaep-256")]
+pub mod rsa_oaep_256_encrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_key;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_public_key;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_algorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_encrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_a128kw_algorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_a128kw_encrypter;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_a256kw_algorithm;
+
+#[cfg(feature = "ecdh-es")]
+pub mod ecdh_es_a256kw_encrypter;
\ No newline at end of file
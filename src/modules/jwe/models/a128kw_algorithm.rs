@@ -0,0 +1,72 @@
+use std::fmt::{Debug, Formatter};
+use rand_core::{OsRng, RngCore};
+use zeroize::Zeroizing;
+use crate::jwe::{key_wrap, JweDecrypter, JweEncrypter, JweError, JweHeader};
+
+/// AES Key Wrap using a 128 bit key (`A128KW`), from
+/// [RFC 7518 section 4.4](https://www.rfc-editor.org/rfc/rfc7518#section-4.4). Every token gets a
+/// random content encryption key, which is wrapped using the shared key.
+#[derive(Clone)]
+pub struct A128KWAlgorithm {
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl A128KWAlgorithm {
+    /// Creates the algorithm using the given key. Fails with [JweError::InvalidKey] if the key is
+    /// not 128 bits.
+    pub fn new(key: &[u8]) -> Result<Self, JweError> {
+        if key.len() != 16 {
+            return Err(JweError::InvalidKey);
+        }
+
+        Ok(A128KWAlgorithm {
+            key: Zeroizing::new(key.to_vec()),
+        })
+    }
+
+    /// Generates a random 128 bit key.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new(vec![0; 16]);
+        OsRng.fill_bytes(&mut key);
+
+        A128KWAlgorithm {
+            key,
+        }
+    }
+
+    /// Returns the key used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl JweEncrypter for A128KWAlgorithm {
+    fn alg(&self) -> &str {
+        "A128KW"
+    }
+
+    fn encrypt_key(
+        &self,
+        _header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        let encrypted_key = key_wrap::wrap(&self.key, &cek)?;
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl JweDecrypter for A128KWAlgorithm {
+    fn alg(&self) -> &str {
+        "A128KW"
+    }
+
+    fn decrypt_key(&self, _header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        key_wrap::unwrap(&self.key, encrypted_key)
+    }
+}
+
+impl Debug for A128KWAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A128KWAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
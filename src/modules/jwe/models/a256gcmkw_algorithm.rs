@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use rand_core::{OsRng, RngCore};
+use zeroize::Zeroizing;
+use crate::jwe::{ContentEncryption, JweDecrypter, JweEncrypter, JweError, JweHeader};
+
+/// Key wrapping with AES-GCM using a 256 bit key (`A256GCMKW`), from
+/// [RFC 7518 section 4.7](https://www.rfc-editor.org/rfc/rfc7518#section-4.7). Every token gets a
+/// random content encryption key, which is encrypted using the shared key. The IV and
+/// authentication tag of the encrypted key are put in the `iv` and `tag` header parameters.
+#[derive(Clone)]
+pub struct A256GCMKWAlgorithm {
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl A256GCMKWAlgorithm {
+    /// Creates the algorithm using the given key. Fails with [JweError::InvalidKey] if the key is
+    /// not 256 bits.
+    pub fn new(key: &[u8]) -> Result<Self, JweError> {
+        if key.len() != 32 {
+            return Err(JweError::InvalidKey);
+        }
+
+        Ok(A256GCMKWAlgorithm {
+            key: Zeroizing::new(key.to_vec()),
+        })
+    }
+
+    /// Generates a random 256 bit key.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new(vec![0; 32]);
+        OsRng.fill_bytes(&mut key);
+
+        A256GCMKWAlgorithm {
+            key,
+        }
+    }
+
+    /// Returns the key used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl JweEncrypter for A256GCMKWAlgorithm {
+    fn alg(&self) -> &str {
+        "A256GCMKW"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        // Encrypting the key is the same as the A256GCM content encryption without any AAD.
+        let iv = ContentEncryption::A256GCM.generate_iv();
+        let (encrypted_key, tag) = ContentEncryption::A256GCM.encrypt(&self.key, &iv, &[], &cek)?;
+
+        header.iv = Some(Cow::Owned(BASE64_URL_SAFE_NO_PAD.encode(iv)));
+        header.tag = Some(Cow::Owned(BASE64_URL_SAFE_NO_PAD.encode(tag)));
+
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl JweDecrypter for A256GCMKWAlgorithm {
+    fn alg(&self) -> &str {
+        "A256GCMKW"
+    }
+
+    fn decrypt_key(&self, header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        let (Some(iv), Some(tag)) = (&header.iv, &header.tag) else {
+            return Err(JweError::DecryptionFailed);
+        };
+
+        let iv = BASE64_URL_SAFE_NO_PAD.decode(iv.as_bytes())
+            .map_err(|_| JweError::DecryptionFailed)?;
+
+        let tag = BASE64_URL_SAFE_NO_PAD.decode(tag.as_bytes())
+            .map_err(|_| JweError::DecryptionFailed)?;
+
+        ContentEncryption::A256GCM.decrypt(&self.key, &iv, &[], encrypted_key, &tag)
+    }
+}
+
+impl Debug for A256GCMKWAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A256GCMKWAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
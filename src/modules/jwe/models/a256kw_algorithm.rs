@@ -0,0 +1,72 @@
+use std::fmt::{Debug, Formatter};
+use rand_core::{OsRng, RngCore};
+use zeroize::Zeroizing;
+use crate::jwe::{key_wrap, JweDecrypter, JweEncrypter, JweError, JweHeader};
+
+/// AES Key Wrap using a 256 bit key (`A256KW`), from
+/// [RFC 7518 section 4.4](https://www.rfc-editor.org/rfc/rfc7518#section-4.4). Every token gets a
+/// random content encryption key, which is wrapped using the shared key.
+#[derive(Clone)]
+pub struct A256KWAlgorithm {
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl A256KWAlgorithm {
+    /// Creates the algorithm using the given key. Fails with [JweError::InvalidKey] if the key is
+    /// not 256 bits.
+    pub fn new(key: &[u8]) -> Result<Self, JweError> {
+        if key.len() != 32 {
+            return Err(JweError::InvalidKey);
+        }
+
+        Ok(A256KWAlgorithm {
+            key: Zeroizing::new(key.to_vec()),
+        })
+    }
+
+    /// Generates a random 256 bit key.
+    pub fn generate() -> Self {
+        let mut key = Zeroizing::new(vec![0; 32]);
+        OsRng.fill_bytes(&mut key);
+
+        A256KWAlgorithm {
+            key,
+        }
+    }
+
+    /// Returns the key used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl JweEncrypter for A256KWAlgorithm {
+    fn alg(&self) -> &str {
+        "A256KW"
+    }
+
+    fn encrypt_key(
+        &self,
+        _header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        let encrypted_key = key_wrap::wrap(&self.key, &cek)?;
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl JweDecrypter for A256KWAlgorithm {
+    fn alg(&self) -> &str {
+        "A256KW"
+    }
+
+    fn decrypt_key(&self, _header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        key_wrap::unwrap(&self.key, encrypted_key)
+    }
+}
+
+impl Debug for A256KWAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A256KWAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
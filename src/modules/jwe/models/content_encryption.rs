@@ -0,0 +1,349 @@
+use std::str::FromStr;
+use aes::{Aes128, Aes256};
+use aes_gcm::{AeadInPlace, Aes128Gcm, Aes256Gcm, KeyInit, Nonce, Tag};
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use cbc::cipher::block_padding::Pkcs7;
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use sha2::{Sha256, Sha512};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+use crate::jwe::JweError;
+
+/// The algorithms used to encrypt the content of a JWE token, from
+/// [RFC 7518 section 5.1](https://www.rfc-editor.org/rfc/rfc7518#section-5.1).
+///
+/// The AES-CBC-HMAC algorithms use a content encryption key twice the size of the AES key, as the
+/// first half is used for the HMAC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncryption {
+    /// AES-GCM using a 128 bit key.
+    A128GCM,
+
+    /// AES-GCM using a 256 bit key.
+    A256GCM,
+
+    /// AES-CBC using a 128 bit key with HMAC SHA-256, from
+    /// [RFC 7518 section 5.2.3](https://www.rfc-editor.org/rfc/rfc7518#section-5.2.3).
+    A128CBCHS256,
+
+    /// AES-CBC using a 256 bit key with HMAC SHA-512, from
+    /// [RFC 7518 section 5.2.5](https://www.rfc-editor.org/rfc/rfc7518#section-5.2.5).
+    A256CBCHS512,
+}
+
+impl ContentEncryption {
+    /// The `enc` header value.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContentEncryption::A128GCM => "A128GCM",
+            ContentEncryption::A256GCM => "A256GCM",
+            ContentEncryption::A128CBCHS256 => "A128CBC-HS256",
+            ContentEncryption::A256CBCHS512 => "A256CBC-HS512",
+        }
+    }
+
+    /// The length of the content encryption key in bytes.
+    pub fn key_len(&self) -> usize {
+        match self {
+            ContentEncryption::A128GCM => 16,
+            ContentEncryption::A256GCM => 32,
+            ContentEncryption::A128CBCHS256 => 32,
+            ContentEncryption::A256CBCHS512 => 64,
+        }
+    }
+
+    fn iv_len(&self) -> usize {
+        match self {
+            ContentEncryption::A128GCM | ContentEncryption::A256GCM => 12,
+            ContentEncryption::A128CBCHS256 | ContentEncryption::A256CBCHS512 => 16,
+        }
+    }
+
+    pub(crate) fn generate_key(&self) -> Zeroizing<Vec<u8>> {
+        let mut key = Zeroizing::new(vec![0; self.key_len()]);
+        OsRng.fill_bytes(&mut key);
+
+        key
+    }
+
+    pub(crate) fn generate_iv(&self) -> Vec<u8> {
+        let mut iv = vec![0; self.iv_len()];
+        OsRng.fill_bytes(&mut iv);
+
+        iv
+    }
+
+    /// Encrypts the plaintext, returning the ciphertext and the authentication tag.
+    pub(crate) fn encrypt(
+        &self,
+        cek: &[u8],
+        iv: &[u8],
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), JweError> {
+        if cek.len() != self.key_len() || iv.len() != self.iv_len() {
+            return Err(JweError::InvalidKey);
+        }
+
+        match self {
+            ContentEncryption::A128GCM => gcm_encrypt::<Aes128Gcm>(cek, iv, aad, plaintext),
+            ContentEncryption::A256GCM => gcm_encrypt::<Aes256Gcm>(cek, iv, aad, plaintext),
+            ContentEncryption::A128CBCHS256 => {
+                cbc_hmac_encrypt::<cbc::Encryptor<Aes128>, Hmac<Sha256>>(cek, iv, aad, plaintext)
+            },
+            ContentEncryption::A256CBCHS512 => {
+                cbc_hmac_encrypt::<cbc::Encryptor<Aes256>, Hmac<Sha512>>(cek, iv, aad, plaintext)
+            },
+        }
+    }
+
+    /// Checks the authentication tag and decrypts the ciphertext. Fails with
+    /// [JweError::DecryptionFailed] if anything does not match.
+    pub(crate) fn decrypt(
+        &self,
+        cek: &[u8],
+        iv: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        if cek.len() != self.key_len() || iv.len() != self.iv_len() {
+            return Err(JweError::DecryptionFailed);
+        }
+
+        match self {
+            ContentEncryption::A128GCM => gcm_decrypt::<Aes128Gcm>(cek, iv, aad, ciphertext, tag),
+            ContentEncryption::A256GCM => gcm_decrypt::<Aes256Gcm>(cek, iv, aad, ciphertext, tag),
+            ContentEncryption::A128CBCHS256 => {
+                cbc_hmac_decrypt::<cbc::Decryptor<Aes128>, Hmac<Sha256>>(cek, iv, aad, ciphertext, tag)
+            },
+            ContentEncryption::A256CBCHS512 => {
+                cbc_hmac_decrypt::<cbc::Decryptor<Aes256>, Hmac<Sha512>>(cek, iv, aad, ciphertext, tag)
+            },
+        }
+    }
+}
+
+impl FromStr for ContentEncryption {
+    type Err = JweError;
+
+    fn from_str(enc: &str) -> Result<Self, Self::Err> {
+        match enc {
+            "A128GCM" => Ok(ContentEncryption::A128GCM),
+            "A256GCM" => Ok(ContentEncryption::A256GCM),
+            "A128CBC-HS256" => Ok(ContentEncryption::A128CBCHS256),
+            "A256CBC-HS512" => Ok(ContentEncryption::A256CBCHS512),
+            enc => Err(JweError::UnsupportedEnc(enc.to_string())),
+        }
+    }
+}
+
+fn gcm_encrypt<C: AeadInPlace + KeyInit>(
+    cek: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), JweError> {
+    let cipher = C::new_from_slice(cek)
+        .map_err(|_| JweError::InvalidKey)?;
+
+    let mut ciphertext = plaintext.to_vec();
+    let tag = cipher.encrypt_in_place_detached(Nonce::from_slice(iv), aad, &mut ciphertext)
+        .map_err(|_| JweError::InvalidKey)?;
+
+    Ok((ciphertext, tag.to_vec()))
+}
+
+fn gcm_decrypt<C: AeadInPlace + KeyInit>(
+    cek: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    if tag.len() != 16 {
+        return Err(JweError::DecryptionFailed);
+    }
+
+    let cipher = C::new_from_slice(cek)
+        .map_err(|_| JweError::DecryptionFailed)?;
+
+    let mut plaintext = Zeroizing::new(ciphertext.to_vec());
+    cipher.decrypt_in_place_detached(Nonce::from_slice(iv), aad, &mut plaintext, Tag::from_slice(tag))
+        .map_err(|_| JweError::DecryptionFailed)?;
+
+    Ok(plaintext)
+}
+
+/// Computes the authentication tag from
+/// [RFC 7518 section 5.2.2.1](https://www.rfc-editor.org/rfc/rfc7518#section-5.2.2.1), which is
+/// the HMAC over the AAD, IV, ciphertext and AAD length in bits, truncated to the size of the MAC
+/// key.
+fn cbc_hmac_tag<M: Mac + KeyInit>(
+    mac_key: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, JweError> {
+    let mut mac = <M as Mac>::new_from_slice(mac_key)
+        .map_err(|_| JweError::InvalidKey)?;
+
+    mac.update(aad);
+    mac.update(iv);
+    mac.update(ciphertext);
+    mac.update(&(aad.len() as u64 * 8).to_be_bytes());
+
+    let mut tag = mac.finalize().into_bytes().to_vec();
+    tag.truncate(mac_key.len());
+
+    Ok(tag)
+}
+
+fn cbc_hmac_encrypt<C: BlockEncryptMut + KeyIvInit, M: Mac + KeyInit>(
+    cek: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), JweError> {
+    let (mac_key, enc_key) = cek.split_at(cek.len() / 2);
+
+    let cipher = C::new_from_slices(enc_key, iv)
+        .map_err(|_| JweError::InvalidKey)?;
+
+    let ciphertext = cipher.encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+    let tag = cbc_hmac_tag::<M>(mac_key, iv, aad, &ciphertext)?;
+
+    Ok((ciphertext, tag))
+}
+
+/// The tag is checked in constant time before anything is decrypted, so invalid padding cannot be
+/// used as a padding oracle.
+fn cbc_hmac_decrypt<C: BlockDecryptMut + KeyIvInit, M: Mac + KeyInit>(
+    cek: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    let (mac_key, enc_key) = cek.split_at(cek.len() / 2);
+
+    let expected_tag = cbc_hmac_tag::<M>(mac_key, iv, aad, ciphertext)
+        .map_err(|_| JweError::DecryptionFailed)?;
+
+    if !bool::from(expected_tag.ct_eq(tag)) {
+        return Err(JweError::DecryptionFailed);
+    }
+
+    let cipher = C::new_from_slices(enc_key, iv)
+        .map_err(|_| JweError::DecryptionFailed)?;
+
+    cipher.decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| JweError::DecryptionFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use crate::jwe::ContentEncryption;
+
+    /// The content encryption of the example in
+    /// [RFC 7516 appendix A.1](https://www.rfc-editor.org/rfc/rfc7516#appendix-A.1).
+    #[test]
+    fn a256gcm_matches_rfc_7516_appendix_a_1() {
+        let cek = BASE64_URL_SAFE_NO_PAD.decode("saH0gFSP4XM_tAP_a5rU9ooHbltwLiJpL4LLLnrqQPw").unwrap();
+        let iv = BASE64_URL_SAFE_NO_PAD.decode("48V1_ALb6US04U3b").unwrap();
+        let aad = b"eyJhbGciOiJSU0EtT0FFUCIsImVuYyI6IkEyNTZHQ00ifQ";
+        let plaintext = b"The true sign of intelligence is not knowledge but imagination.";
+
+        let (ciphertext, tag) = ContentEncryption::A256GCM.encrypt(&cek, &iv, aad, plaintext).unwrap();
+
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(&ciphertext), "5eym8TW_c8SuK0ltJ3rpYIzOeDQz7TALvtu6UG9oMo4vpzs9tX_EFShS8iB7j6jiSdiwkIr3ajwQzaBtQD_A");
+        assert_eq!(BASE64_URL_SAFE_NO_PAD.encode(&tag), "XFBoMYUZodetZdvTiFvSkQ");
+
+        let decrypted = ContentEncryption::A256GCM.decrypt(&cek, &iv, aad, &ciphertext, &tag).unwrap();
+        assert_eq!(decrypted.as_slice(), plaintext);
+
+        let mut tampered = tag.clone();
+        tampered[0] ^= 1;
+
+        assert!(ContentEncryption::A256GCM.decrypt(&cek, &iv, aad, &ciphertext, &tampered).is_err());
+        assert!(ContentEncryption::A256GCM.decrypt(&cek, &iv, b"other", &ciphertext, &tag).is_err());
+        assert!(ContentEncryption::A256GCM.decrypt(&cek, &iv, aad, &ciphertext, &tag[..15]).is_err());
+    }
+
+    /// The plaintext, IV and AAD shared by the examples in
+    /// [RFC 7518 appendix B](https://www.rfc-editor.org/rfc/rfc7518#appendix-B).
+    fn cbc_hmac_matches_rfc_7518(enc: ContentEncryption, expected_ciphertext: &[u8], expected_tag: &[u8]) {
+        let cek = (0..enc.key_len() as u8).collect::<Vec<u8>>();
+        let iv = [
+            0x1a, 0xf3, 0x8c, 0x2d, 0xc2, 0xb9, 0x6f, 0xfd, 0xd8, 0x66, 0x94, 0x09, 0x23, 0x41, 0xbc, 0x04,
+        ];
+
+        let aad = b"The second principle of Auguste Kerckhoffs";
+        let plaintext = b"A cipher system must not be required to be secret, and it must be able to fall into the hands of the enemy without inconvenience";
+
+        let (ciphertext, tag) = enc.encrypt(&cek, &iv, aad, plaintext).unwrap();
+
+        assert_eq!(ciphertext, expected_ciphertext);
+        assert_eq!(tag, expected_tag);
+
+        let decrypted = enc.decrypt(&cek, &iv, aad, &ciphertext, &tag).unwrap();
+        assert_eq!(decrypted.as_slice(), plaintext);
+
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+
+        assert!(enc.decrypt(&cek, &iv, aad, &tampered, &tag).is_err());
+        assert!(enc.decrypt(&cek, &iv, b"other", &ciphertext, &tag).is_err());
+        assert!(enc.decrypt(&cek, &iv, aad, &ciphertext, &tag[..tag.len() - 1]).is_err());
+        assert!(enc.decrypt(&cek, &iv, aad, &ciphertext, &[]).is_err());
+    }
+
+    /// [RFC 7518 appendix B.1](https://www.rfc-editor.org/rfc/rfc7518#appendix-B.1).
+    #[test]
+    fn a128cbc_hs256_matches_rfc_7518_appendix_b_1() {
+        let ciphertext = [
+            0xc8, 0x0e, 0xdf, 0xa3, 0x2d, 0xdf, 0x39, 0xd5, 0xef, 0x00, 0xc0, 0xb4, 0x68, 0x83, 0x42, 0x79,
+            0xa2, 0xe4, 0x6a, 0x1b, 0x80, 0x49, 0xf7, 0x92, 0xf7, 0x6b, 0xfe, 0x54, 0xb9, 0x03, 0xa9, 0xc9,
+            0xa9, 0x4a, 0xc9, 0xb4, 0x7a, 0xd2, 0x65, 0x5c, 0x5f, 0x10, 0xf9, 0xae, 0xf7, 0x14, 0x27, 0xe2,
+            0xfc, 0x6f, 0x9b, 0x3f, 0x39, 0x9a, 0x22, 0x14, 0x89, 0xf1, 0x63, 0x62, 0xc7, 0x03, 0x23, 0x36,
+            0x09, 0xd4, 0x5a, 0xc6, 0x98, 0x64, 0xe3, 0x32, 0x1c, 0xf8, 0x29, 0x35, 0xac, 0x40, 0x96, 0xc8,
+            0x6e, 0x13, 0x33, 0x14, 0xc5, 0x40, 0x19, 0xe8, 0xca, 0x79, 0x80, 0xdf, 0xa4, 0xb9, 0xcf, 0x1b,
+            0x38, 0x4c, 0x48, 0x6f, 0x3a, 0x54, 0xc5, 0x10, 0x78, 0x15, 0x8e, 0xe5, 0xd7, 0x9d, 0xe5, 0x9f,
+            0xbd, 0x34, 0xd8, 0x48, 0xb3, 0xd6, 0x95, 0x50, 0xa6, 0x76, 0x46, 0x34, 0x44, 0x27, 0xad, 0xe5,
+            0x4b, 0x88, 0x51, 0xff, 0xb5, 0x98, 0xf7, 0xf8, 0x00, 0x74, 0xb9, 0x47, 0x3c, 0x82, 0xe2, 0xdb,
+        ];
+
+        let tag = [
+            0x65, 0x2c, 0x3f, 0xa3, 0x6b, 0x0a, 0x7c, 0x5b, 0x32, 0x19, 0xfa, 0xb3, 0xa3, 0x0b, 0xc1, 0xc4,
+        ];
+
+        cbc_hmac_matches_rfc_7518(ContentEncryption::A128CBCHS256, &ciphertext, &tag);
+    }
+
+    /// [RFC 7518 appendix B.3](https://www.rfc-editor.org/rfc/rfc7518#appendix-B.3).
+    #[test]
+    fn a256cbc_hs512_matches_rfc_7518_appendix_b_3() {
+        let ciphertext = [
+            0x4a, 0xff, 0xaa, 0xad, 0xb7, 0x8c, 0x31, 0xc5, 0xda, 0x4b, 0x1b, 0x59, 0x0d, 0x10, 0xff, 0xbd,
+            0x3d, 0xd8, 0xd5, 0xd3, 0x02, 0x42, 0x35, 0x26, 0x91, 0x2d, 0xa0, 0x37, 0xec, 0xbc, 0xc7, 0xbd,
+            0x82, 0x2c, 0x30, 0x1d, 0xd6, 0x7c, 0x37, 0x3b, 0xcc, 0xb5, 0x84, 0xad, 0x3e, 0x92, 0x79, 0xc2,
+            0xe6, 0xd1, 0x2a, 0x13, 0x74, 0xb7, 0x7f, 0x07, 0x75, 0x53, 0xdf, 0x82, 0x94, 0x10, 0x44, 0x6b,
+            0x36, 0xeb, 0xd9, 0x70, 0x66, 0x29, 0x6a, 0xe6, 0x42, 0x7e, 0xa7, 0x5c, 0x2e, 0x08, 0x46, 0xa1,
+            0x1a, 0x09, 0xcc, 0xf5, 0x37, 0x0d, 0xc8, 0x0b, 0xfe, 0xcb, 0xad, 0x28, 0xc7, 0x3f, 0x09, 0xb3,
+            0xa3, 0xb7, 0x5e, 0x66, 0x2a, 0x25, 0x94, 0x41, 0x0a, 0xe4, 0x96, 0xb2, 0xe2, 0xe6, 0x60, 0x9e,
+            0x31, 0xe6, 0xe0, 0x2c, 0xc8, 0x37, 0xf0, 0x53, 0xd2, 0x1f, 0x37, 0xff, 0x4f, 0x51, 0x95, 0x0b,
+            0xbe, 0x26, 0x38, 0xd0, 0x9d, 0xd7, 0xa4, 0x93, 0x09, 0x30, 0x80, 0x6d, 0x07, 0x03, 0xb1, 0xf6,
+        ];
+
+        let tag = [
+            0x4d, 0xd3, 0xb4, 0xc0, 0x88, 0xa7, 0xf4, 0x5c, 0x21, 0x68, 0x39, 0x64, 0x5b, 0x20, 0x12, 0xbf,
+            0x2e, 0x62, 0x69, 0xa8, 0xc5, 0x6a, 0x81, 0x6d, 0xbc, 0x1b, 0x26, 0x77, 0x61, 0x95, 0x5b, 0xc5,
+        ];
+
+        cbc_hmac_matches_rfc_7518(ContentEncryption::A256CBCHS512, &ciphertext, &tag);
+    }
+}
\ No newline at end of file
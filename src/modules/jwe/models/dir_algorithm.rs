@@ -0,0 +1,69 @@
+use std::fmt::{Debug, Formatter};
+use zeroize::Zeroizing;
+use crate::jwe::{ContentEncryption, JweDecrypter, JweEncrypter, JweError, JweHeader};
+
+/// Direct encryption (`dir`), where the shared symmetric key is used as the content encryption key.
+/// The key must have the length the `enc` of the token needs.
+#[derive(Clone)]
+pub struct DirAlgorithm {
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl DirAlgorithm {
+    pub fn new(key: &[u8]) -> Self {
+        DirAlgorithm {
+            key: Zeroizing::new(key.to_vec()),
+        }
+    }
+
+    /// Generates a random key for the given content encryption.
+    pub fn generate(enc: ContentEncryption) -> Self {
+        DirAlgorithm {
+            key: enc.generate_key(),
+        }
+    }
+
+    /// Returns the key used for this algorithm.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl JweEncrypter for DirAlgorithm {
+    fn alg(&self) -> &str {
+        "dir"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        _cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        if self.key.len() != header.content_encryption()?.key_len() {
+            return Err(JweError::InvalidKey);
+        }
+
+        Ok((self.key.clone(), vec![]))
+    }
+}
+
+impl JweDecrypter for DirAlgorithm {
+    fn alg(&self) -> &str {
+        "dir"
+    }
+
+    fn decrypt_key(&self, _header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        // The encrypted key must be empty when using direct encryption.
+        if !encrypted_key.is_empty() {
+            return Err(JweError::DecryptionFailed);
+        }
+
+        Ok(self.key.clone())
+    }
+}
+
+impl Debug for DirAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DirAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
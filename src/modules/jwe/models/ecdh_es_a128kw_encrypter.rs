@@ -0,0 +1,37 @@
+use zeroize::Zeroizing;
+use crate::jwe::{ecdh_es, EcdhPublicKey, JweEncrypter, JweError, JweHeader};
+use crate::jwe::ecdh_es::KeyAgreement;
+
+/// Encrypt-only counterpart of [ECDHESA128KWAlgorithm](crate::jwe::ECDHESA128KWAlgorithm) which only needs
+/// the public key of the recipient.
+#[derive(Debug, Clone)]
+pub struct ECDHESA128KWEncrypter {
+    key: EcdhPublicKey,
+}
+
+impl ECDHESA128KWEncrypter {
+    pub fn new(key: impl Into<EcdhPublicKey>) -> Self {
+        ECDHESA128KWEncrypter {
+            key: key.into(),
+        }
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &EcdhPublicKey {
+        &self.key
+    }
+}
+
+impl JweEncrypter for ECDHESA128KWEncrypter {
+    fn alg(&self) -> &str {
+        "ECDH-ES+A128KW"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        ecdh_es::encrypt_key(KeyAgreement::A128KW, &self.key, header, cek)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use std::fmt::{Debug, Formatter};
+use zeroize::Zeroizing;
+use crate::jwe::{ecdh_es, EcdhKey, JweDecrypter, JweEncrypter, JweError, JweHeader, ECDHESA256KWEncrypter};
+use crate::jwe::ecdh_es::KeyAgreement;
+
+/// Elliptic Curve Diffie-Hellman Ephemeral Static key agreement with A256KW (`ECDH-ES+A256KW`),
+/// from [RFC 7518 section 4.6](https://www.rfc-editor.org/rfc/rfc7518#section-4.6). The key agreed
+/// with an ephemeral key of the sender wraps a random content encryption key.
+///
+/// Both P-256 and X25519 keys are supported, see [EcdhKey].
+#[derive(Clone)]
+pub struct ECDHESA256KWAlgorithm {
+    key: EcdhKey,
+}
+
+impl ECDHESA256KWAlgorithm {
+    pub fn new(key: impl Into<EcdhKey>) -> Self {
+        ECDHESA256KWAlgorithm {
+            key: key.into(),
+        }
+    }
+
+    /// Returns the encrypt-only counterpart of this algorithm, which only holds the public key.
+    pub fn encrypter(&self) -> ECDHESA256KWEncrypter {
+        ECDHESA256KWEncrypter::new(self.key.public_key())
+    }
+
+    /// Returns the private key used for this algorithm.
+    pub fn key(&self) -> &EcdhKey {
+        &self.key
+    }
+}
+
+impl JweEncrypter for ECDHESA256KWAlgorithm {
+    fn alg(&self) -> &str {
+        "ECDH-ES+A256KW"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        ecdh_es::encrypt_key(KeyAgreement::A256KW, &self.key.public_key(), header, cek)
+    }
+}
+
+impl JweDecrypter for ECDHESA256KWAlgorithm {
+    fn alg(&self) -> &str {
+        "ECDH-ES+A256KW"
+    }
+
+    fn decrypt_key(&self, header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        ecdh_es::decrypt_key(KeyAgreement::A256KW, &self.key, header, encrypted_key)
+    }
+}
+
+impl Debug for ECDHESA256KWAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECDHESA256KWAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use zeroize::Zeroizing;
+use crate::jwe::{ecdh_es, EcdhPublicKey, JweEncrypter, JweError, JweHeader};
+use crate::jwe::ecdh_es::KeyAgreement;
+
+/// Encrypt-only counterpart of [ECDHESA256KWAlgorithm](crate::jwe::ECDHESA256KWAlgorithm) which only needs
+/// the public key of the recipient.
+#[derive(Debug, Clone)]
+pub struct ECDHESA256KWEncrypter {
+    key: EcdhPublicKey,
+}
+
+impl ECDHESA256KWEncrypter {
+    pub fn new(key: impl Into<EcdhPublicKey>) -> Self {
+        ECDHESA256KWEncrypter {
+            key: key.into(),
+        }
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &EcdhPublicKey {
+        &self.key
+    }
+}
+
+impl JweEncrypter for ECDHESA256KWEncrypter {
+    fn alg(&self) -> &str {
+        "ECDH-ES+A256KW"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        ecdh_es::encrypt_key(KeyAgreement::A256KW, &self.key, header, cek)
+    }
+}
\ No newline at end of file
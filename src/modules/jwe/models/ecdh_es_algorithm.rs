@@ -0,0 +1,62 @@
+use std::fmt::{Debug, Formatter};
+use zeroize::Zeroizing;
+use crate::jwe::{ecdh_es, EcdhKey, JweDecrypter, JweEncrypter, JweError, JweHeader, ECDHESEncrypter};
+use crate::jwe::ecdh_es::KeyAgreement;
+
+/// Elliptic Curve Diffie-Hellman Ephemeral Static key agreement (`ECDH-ES`), from
+/// [RFC 7518 section 4.6](https://www.rfc-editor.org/rfc/rfc7518#section-4.6). The key agreed
+/// with an ephemeral key of the sender is used directly as the content encryption key.
+///
+/// Both P-256 and X25519 keys are supported, see [EcdhKey].
+#[derive(Clone)]
+pub struct ECDHESAlgorithm {
+    key: EcdhKey,
+}
+
+impl ECDHESAlgorithm {
+    pub fn new(key: impl Into<EcdhKey>) -> Self {
+        ECDHESAlgorithm {
+            key: key.into(),
+        }
+    }
+
+    /// Returns the encrypt-only counterpart of this algorithm, which only holds the public key.
+    pub fn encrypter(&self) -> ECDHESEncrypter {
+        ECDHESEncrypter::new(self.key.public_key())
+    }
+
+    /// Returns the private key used for this algorithm.
+    pub fn key(&self) -> &EcdhKey {
+        &self.key
+    }
+}
+
+impl JweEncrypter for ECDHESAlgorithm {
+    fn alg(&self) -> &str {
+        "ECDH-ES"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        ecdh_es::encrypt_key(KeyAgreement::Direct, &self.key.public_key(), header, cek)
+    }
+}
+
+impl JweDecrypter for ECDHESAlgorithm {
+    fn alg(&self) -> &str {
+        "ECDH-ES"
+    }
+
+    fn decrypt_key(&self, header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        ecdh_es::decrypt_key(KeyAgreement::Direct, &self.key, header, encrypted_key)
+    }
+}
+
+impl Debug for ECDHESAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ECDHESAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use zeroize::Zeroizing;
+use crate::jwe::{ecdh_es, EcdhPublicKey, JweEncrypter, JweError, JweHeader};
+use crate::jwe::ecdh_es::KeyAgreement;
+
+/// Encrypt-only counterpart of [ECDHESAlgorithm](crate::jwe::ECDHESAlgorithm) which only needs
+/// the public key of the recipient.
+#[derive(Debug, Clone)]
+pub struct ECDHESEncrypter {
+    key: EcdhPublicKey,
+}
+
+impl ECDHESEncrypter {
+    pub fn new(key: impl Into<EcdhPublicKey>) -> Self {
+        ECDHESEncrypter {
+            key: key.into(),
+        }
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &EcdhPublicKey {
+        &self.key
+    }
+}
+
+impl JweEncrypter for ECDHESEncrypter {
+    fn alg(&self) -> &str {
+        "ECDH-ES"
+    }
+
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        ecdh_es::encrypt_key(KeyAgreement::Direct, &self.key, header, cek)
+    }
+}
\ No newline at end of file
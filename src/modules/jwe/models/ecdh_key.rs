@@ -0,0 +1,80 @@
+use std::fmt::{Debug, Formatter};
+use rand_core::OsRng;
+use x25519_dalek::StaticSecret;
+use zeroize::Zeroizing;
+use crate::jwe::EcdhPublicKey;
+
+/// The private key of the recipient for the ECDH-ES key agreement algorithms. Keys can be created
+/// from the key types of the curve crates, or converted from and to a
+/// [Jwk](crate::jwk::Jwk).
+#[derive(Clone)]
+pub enum EcdhKey {
+    /// A key on the NIST P-256 curve (`crv` "P-256").
+    P256(p256::SecretKey),
+
+    /// An X25519 key from [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037) (`crv` "X25519").
+    X25519(StaticSecret),
+}
+
+impl EcdhKey {
+    /// Generates a random P-256 key.
+    pub fn generate_p256() -> Self {
+        EcdhKey::P256(p256::SecretKey::random(&mut OsRng))
+    }
+
+    /// Generates a random X25519 key.
+    pub fn generate_x25519() -> Self {
+        EcdhKey::X25519(StaticSecret::random_from_rng(OsRng))
+    }
+
+    /// Returns the public key belonging to this key, which senders use to encrypt tokens.
+    pub fn public_key(&self) -> EcdhPublicKey {
+        match self {
+            EcdhKey::P256(key) => EcdhPublicKey::P256(key.public_key()),
+            EcdhKey::X25519(key) => EcdhPublicKey::X25519(key.into()),
+        }
+    }
+
+    /// Computes the shared secret with the public key of the other party. Returns `None` when the
+    /// keys are not on the same curve, or when the X25519 public key is a low order point.
+    pub(crate) fn diffie_hellman(&self, public_key: &EcdhPublicKey) -> Option<Zeroizing<Vec<u8>>> {
+        match (self, public_key) {
+            (EcdhKey::P256(key), EcdhPublicKey::P256(public_key)) => {
+                let shared = p256::ecdh::diffie_hellman(key.to_nonzero_scalar(), public_key.as_affine());
+                Some(Zeroizing::new(shared.raw_secret_bytes().to_vec()))
+            },
+            (EcdhKey::X25519(key), EcdhPublicKey::X25519(public_key)) => {
+                let shared = key.diffie_hellman(public_key);
+
+                // Low order points result in a shared secret of all zeros.
+                if !shared.was_contributory() {
+                    return None;
+                }
+
+                Some(Zeroizing::new(shared.as_bytes().to_vec()))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl From<p256::SecretKey> for EcdhKey {
+    fn from(key: p256::SecretKey) -> Self {
+        EcdhKey::P256(key)
+    }
+}
+
+impl From<StaticSecret> for EcdhKey {
+    fn from(key: StaticSecret) -> Self {
+        EcdhKey::X25519(key)
+    }
+}
+
+impl Debug for EcdhKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EcdhKey::P256(_) => write!(f, "EcdhKey::P256 {{ .. }}"),
+            EcdhKey::X25519(_) => write!(f, "EcdhKey::X25519 {{ .. }}"),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use rand_core::OsRng;
+use x25519_dalek::{PublicKey, StaticSecret};
+use crate::jwe::EcdhKey;
+
+/// The public key of the recipient for the ECDH-ES key agreement algorithms, which is also the type
+/// of the ephemeral key in the `epk` header parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EcdhPublicKey {
+    /// A key on the NIST P-256 curve (`crv` "P-256").
+    P256(p256::PublicKey),
+
+    /// An X25519 key from [RFC 8037](https://www.rfc-editor.org/rfc/rfc8037) (`crv` "X25519").
+    X25519(PublicKey),
+}
+
+impl EcdhPublicKey {
+    /// Generates a random ephemeral key on the same curve as this key.
+    pub(crate) fn generate_ephemeral(&self) -> EcdhKey {
+        match self {
+            EcdhPublicKey::P256(_) => EcdhKey::P256(p256::SecretKey::random(&mut OsRng)),
+            EcdhPublicKey::X25519(_) => EcdhKey::X25519(StaticSecret::random_from_rng(OsRng)),
+        }
+    }
+}
+
+impl From<p256::PublicKey> for EcdhPublicKey {
+    fn from(key: p256::PublicKey) -> Self {
+        EcdhPublicKey::P256(key)
+    }
+}
+
+impl From<PublicKey> for EcdhPublicKey {
+    fn from(key: PublicKey) -> Self {
+        EcdhPublicKey::X25519(key)
+    }
+}
\ No newline at end of file
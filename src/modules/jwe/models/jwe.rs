@@ -0,0 +1,135 @@
+use std::fmt::{Debug, Formatter};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use crate::jwe::{compact, ContentEncryption, JweDecrypter, JweEncrypter, JweError, JweHeader};
+use crate::token::{JwtClaims, JwtError};
+
+/// Representation of a (decrypted) JWE token, the encrypted counterpart of
+/// [Jwt](crate::token::Jwt). The payload and claims are encrypted using the JWE compact
+/// serialization from [RFC 7516](https://www.rfc-editor.org/rfc/rfc7516).
+pub struct Jwe<T>
+where T : Serialize + for<'a> Deserialize<'a>,
+{
+    header: JweHeader<'static>,
+    payload: T,
+    claims: JwtClaims,
+}
+
+impl<T> Jwe<T>
+where T : Serialize + for<'a> Deserialize<'a>,
+{
+    /// Takes the JWE instance, encrypts it for the given key, and returns the string representation
+    /// for the token.
+    pub fn into_token<K: JweEncrypter + ?Sized>(self, key: &K) -> Result<String, JweError> {
+        let bytes = self.claims.to_payload(&self.payload)?;
+
+        compact::encrypt(self.header, &bytes, key)
+    }
+
+    /// Decrypts the given string token with the given key. Returns a JWE token instance with the
+    /// expected payload. Note that this does not check any claims. To verify basic expiry claims
+    /// you can use [Jwe::verify_now] or you can further verify the token using [Jwe::against] or
+    /// [Jwe::guard].
+    pub fn check<K: JweDecrypter + ?Sized>(token: &str, key: &K) -> Result<Jwe<T>, JweError> {
+        let (header, plaintext) = compact::decrypt(token, key)?;
+
+        let payload: T = serde_json::from_slice(&plaintext)?;
+
+        // Unwrap or default as these would then be checked later.
+        let claims = serde_json::from_slice(&plaintext)
+            .unwrap_or_default();
+
+        Ok(Jwe {
+            header,
+            payload,
+            claims,
+        })
+    }
+
+    /// Largely the same as [Jwe::check], but also verifies basic expiry claims. You can further
+    /// verify the token using [Jwe::against] or [Jwe::guard].
+    pub fn verify_now<K: JweDecrypter + ?Sized>(token: &str, key: &K) -> Result<Jwe<T>, JweError> {
+        let jwe = Jwe::<T>::check(token, key)?
+            .against(&JwtClaims::now())?;
+
+        Ok(jwe)
+    }
+
+    /// Verifies the token against the given claims and returns `Self`. To verify claims on a
+    /// reference use [Jwe::guard].
+    pub fn against(self, other: &JwtClaims) -> Result<Self, JwtError> {
+        self.claims.verify(other)?;
+        Ok(self)
+    }
+
+    /// Verifies the token against the given claims. To verify claims 'in-line' use [Jwe::against].
+    pub fn guard(&self, other: &JwtClaims) -> Result<(), JwtError> {
+        self.claims.verify(other)
+    }
+
+    /// Sets the algorithm used to encrypt the content, which is A256GCM by default.
+    pub fn with_enc(mut self, enc: ContentEncryption) -> Self {
+        self.header = self.header.with_enc(enc);
+        self
+    }
+
+    /// Overwrites the header that is used when issuing the token. See [JweHeader::default] for the
+    /// default header.
+    pub fn with_header(mut self, header: JweHeader<'static>) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Overwrites the current claims with the given claims.
+    pub fn with_claims(mut self, claims: JwtClaims) -> Self {
+        self.claims = claims;
+        self
+    }
+
+    pub fn with_merge(mut self, other: &JwtClaims) -> Self {
+        self.claims = self.claims.with_merge(other);
+        self
+    }
+
+    /// Returns the header of the token. For a checked token this is the header it was encrypted
+    /// with.
+    pub fn header(&self) -> &JweHeader<'static> {
+        &self.header
+    }
+
+    /// Returns a reference to the payload for this token.
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    /// Consumes the token and returns the payload.
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+}
+
+impl Default for Jwe<Value> {
+    fn default() -> Self {
+        Jwe::new(json!({}))
+    }
+}
+
+impl<T> Debug for Jwe<T>
+where T : Serialize + for<'a> Deserialize<'a> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Jwe {{ header: {:?}, claims: {:?}, payload: {:?} }}", self.header, self.claims, self.payload)
+    }
+}
+
+impl<T> Jwe<T>
+where T : Serialize + for<'a> Deserialize<'a>
+{
+    pub fn new(payload: T) -> Self {
+        Jwe {
+            header: JweHeader::default(),
+            payload,
+            claims: JwtClaims::default(),
+        }
+    }
+}
\ No newline at end of file
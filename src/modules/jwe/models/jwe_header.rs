@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::jwe::{ContentEncryption, JweError};
+use crate::jwk::Jwk;
+
+/// The protected header of a JWE token, with the header parameters from
+/// [RFC 7516 section 4.1](https://www.rfc-editor.org/rfc/rfc7516#section-4.1). Any other parameters
+/// end up in [JweHeader::extra].
+///
+/// The `alg` is always set by the key management algorithm that encrypts the token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JweHeader<'a> {
+    /// The algorithm used to encrypt the content encryption key. Possible values can be found in
+    /// [RFC 7518](https://www.rfc-editor.org/rfc/rfc7518#section-4.1).
+    pub alg: Cow<'a, str>,
+
+    /// The algorithm used to encrypt the content, see [ContentEncryption].
+    pub enc: Cow<'a, str>,
+
+    /// Compression of the content before encrypting, which is not supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<Cow<'a, str>>,
+
+    /// The type of token. Defaults to "JWT" when issuing tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typ: Option<Cow<'a, str>>,
+
+    /// The type of the content, which is "JWT" when the content is a nested (signed) JWT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cty: Option<Cow<'a, str>>,
+
+    /// The ID of the key the token was encrypted to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<Cow<'a, str>>,
+
+    /// The base64url encoded IV used to encrypt the key, for the AES-GCM key wrapping algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iv: Option<Cow<'a, str>>,
+
+    /// The base64url encoded authentication tag of the encrypted key, for the AES-GCM key wrapping
+    /// algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Cow<'a, str>>,
+
+    /// The ephemeral public key created by the sender, for the ECDH-ES key agreement algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epk: Option<Jwk>,
+
+    /// Base64url encoded information about the sender (agreement PartyUInfo), which is included
+    /// when deriving the key for the ECDH-ES key agreement algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apu: Option<Cow<'a, str>>,
+
+    /// Base64url encoded information about the recipient (agreement PartyVInfo), which is included
+    /// when deriving the key for the ECDH-ES key agreement algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apv: Option<Cow<'a, str>>,
+
+    /// Header parameters that the receiver must understand. As none of the extensions are
+    /// supported, tokens with this parameter are rejected with [JweError::UnsupportedCrit].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crit: Option<Vec<String>>,
+
+    /// Any other (private) header parameters.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JweHeader<'_> {
+    /// Returns the content encryption from the `enc` parameter. Fails with
+    /// [JweError::UnsupportedEnc] for unknown values.
+    pub fn content_encryption(&self) -> Result<ContentEncryption, JweError> {
+        self.enc.parse()
+    }
+
+    /// Sets the `enc` parameter on the header.
+    pub fn with_enc(mut self, enc: ContentEncryption) -> Self {
+        self.enc = Cow::Borrowed(enc.name());
+        self
+    }
+
+    /// Sets the `typ` parameter on the header.
+    pub fn with_typ(mut self, typ: impl Into<String>) -> Self {
+        self.typ = Some(Cow::Owned(typ.into()));
+        self
+    }
+
+    /// Sets the `cty` parameter on the header.
+    pub fn with_cty(mut self, cty: impl Into<String>) -> Self {
+        self.cty = Some(Cow::Owned(cty.into()));
+        self
+    }
+
+    /// Sets the `kid` parameter on the header.
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(Cow::Owned(kid.into()));
+        self
+    }
+
+    /// Sets the `apu` parameter on the header, which is base64url encoded.
+    pub fn with_apu(mut self, apu: impl AsRef<[u8]>) -> Self {
+        self.apu = Some(Cow::Owned(BASE64_URL_SAFE_NO_PAD.encode(apu)));
+        self
+    }
+
+    /// Sets the `apv` parameter on the header, which is base64url encoded.
+    pub fn with_apv(mut self, apv: impl AsRef<[u8]>) -> Self {
+        self.apv = Some(Cow::Owned(BASE64_URL_SAFE_NO_PAD.encode(apv)));
+        self
+    }
+
+    /// Sets a custom parameter on the header.
+    pub fn with_param(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// The header used when issuing tokens, which uses A256GCM for the content and has `typ` set to
+/// "JWT". The `alg` is empty until the token is encrypted.
+impl Default for JweHeader<'_> {
+    fn default() -> Self {
+        JweHeader {
+            alg: Cow::Borrowed(""),
+            enc: Cow::Borrowed(ContentEncryption::A256GCM.name()),
+            zip: None,
+            typ: Some(Cow::Borrowed("JWT")),
+            cty: None,
+            kid: None,
+            iv: None,
+            tag: None,
+            epk: None,
+            apu: None,
+            apv: None,
+            crit: None,
+            extra: Map::new(),
+        }
+    }
+}
\ No newline at end of file
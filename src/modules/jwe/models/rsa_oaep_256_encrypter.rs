@@ -0,0 +1,64 @@
+use std::fmt::{Debug, Formatter};
+use rsa::{Oaep, RsaPublicKey};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+use crate::algorithm::KeyError;
+use crate::algorithm::formats;
+use crate::jwe::{rsa_oaep, JweEncrypter, JweError, JweHeader};
+
+/// Encrypt-only counterpart of [RSAOAEP256Algorithm](crate::jwe::RSAOAEP256Algorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct RSAOAEP256Encrypter {
+    inner: RsaPublicKey,
+}
+
+impl RSAOAEP256Encrypter {
+    pub fn new(key: RsaPublicKey) -> Self {
+        RSAOAEP256Encrypter {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not an RSA key.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RSAOAEP256Encrypter::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RSAOAEP256Encrypter::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &RsaPublicKey {
+        &self.inner
+    }
+}
+
+impl JweEncrypter for RSAOAEP256Encrypter {
+    fn alg(&self) -> &str {
+        "RSA-OAEP-256"
+    }
+
+    fn encrypt_key(
+        &self,
+        _header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        let encrypted_key = rsa_oaep::encrypt(&self.inner, Oaep::new::<Sha256>(), &cek)?;
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl Debug for RSAOAEP256Encrypter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RSAOAEP256Encrypter {{ .. }}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::fmt::{Debug, Formatter};
+use rsa::{Oaep, RsaPrivateKey};
+use sha1::Sha1;
+use zeroize::Zeroizing;
+use crate::algorithm::KeyError;
+use crate::algorithm::formats;
+use crate::jwe::{rsa_oaep, JweDecrypter, JweEncrypter, JweError, JweHeader, RSAOAEPEncrypter};
+
+/// RSAES-OAEP using SHA-1 and MGF1 with SHA-1 (`RSA-OAEP`), from
+/// [RFC 7518 section 4.3](https://www.rfc-editor.org/rfc/rfc7518#section-4.3). Every token gets a
+/// random content encryption key, which is encrypted using the public key.
+///
+/// This uses the same RSA keys as [RS256Algorithm](crate::algorithm::RS256Algorithm), which are
+/// loaded in the same way. An existing key can be shared using
+/// `RSAOAEPAlgorithm::new(rs256.key().as_ref().clone())`.
+#[derive(Clone)]
+pub struct RSAOAEPAlgorithm {
+    inner: RsaPrivateKey,
+}
+
+impl RSAOAEPAlgorithm {
+    pub fn new(key: RsaPrivateKey) -> Self {
+        RSAOAEPAlgorithm {
+            inner: key,
+        }
+    }
+
+    /// Generates a new RSA key with a modulus of `bits` bits. Fails with [KeyError::KeyTooSmall]
+    /// if `bits` is smaller than 2048.
+    pub fn generate(bits: usize) -> Result<Self, KeyError> {
+        Ok(RSAOAEPAlgorithm::new(formats::generate_rsa_key(bits)?))
+    }
+
+    /// Loads the private key from a PEM encoded PKCS#1 (`RSA PRIVATE KEY`) or PKCS#8 (`PRIVATE
+    /// KEY`) key. Fails with [KeyError::KeyTypeMismatch] if the key is not an RSA key.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RSAOAEPAlgorithm::new(formats::private_key_from_pem(pem)?))
+    }
+
+    /// Loads the private key from a DER encoded PKCS#1 or PKCS#8 key.
+    pub fn from_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RSAOAEPAlgorithm::new(formats::private_key_from_der(der)?))
+    }
+
+    /// Loads the private key from a PEM encoded encrypted PKCS#8 (`ENCRYPTED PRIVATE KEY`) key.
+    #[cfg(feature = "encrypted-pem")]
+    pub fn from_encrypted_pem(pem: &str, passphrase: impl AsRef<[u8]>) -> Result<Self, KeyError> {
+        Ok(RSAOAEPAlgorithm::new(formats::private_key_from_encrypted_pem(pem, passphrase)?))
+    }
+
+    /// Exports the private key as a PEM encoded PKCS#8 (`PRIVATE KEY`) key, which can be loaded
+    /// again using [RSAOAEPAlgorithm::from_pem].
+    pub fn to_pem(&self) -> Result<Zeroizing<String>, KeyError> {
+        formats::private_key_to_pem(&self.inner)
+    }
+
+    /// Returns the encrypt-only counterpart of this algorithm, which only holds the public key.
+    pub fn encrypter(&self) -> RSAOAEPEncrypter {
+        RSAOAEPEncrypter::new(self.inner.to_public_key())
+    }
+
+    /// Returns the private key used for this algorithm.
+    pub fn key(&self) -> &RsaPrivateKey {
+        &self.inner
+    }
+}
+
+impl JweEncrypter for RSAOAEPAlgorithm {
+    fn alg(&self) -> &str {
+        "RSA-OAEP"
+    }
+
+    fn encrypt_key(
+        &self,
+        _header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        let encrypted_key = rsa_oaep::encrypt(self.inner.as_ref(), Oaep::new::<Sha1>(), &cek)?;
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl JweDecrypter for RSAOAEPAlgorithm {
+    fn alg(&self) -> &str {
+        "RSA-OAEP"
+    }
+
+    fn decrypt_key(&self, header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError> {
+        rsa_oaep::decrypt(&self.inner, Oaep::new::<Sha1>(), header, encrypted_key)
+    }
+}
+
+impl Debug for RSAOAEPAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RSAOAEPAlgorithm {{ .. }}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::fmt::{Debug, Formatter};
+use rsa::{Oaep, RsaPublicKey};
+use sha1::Sha1;
+use zeroize::Zeroizing;
+use crate::algorithm::KeyError;
+use crate::algorithm::formats;
+use crate::jwe::{rsa_oaep, JweEncrypter, JweError, JweHeader};
+
+/// Encrypt-only counterpart of [RSAOAEPAlgorithm](crate::jwe::RSAOAEPAlgorithm) which only needs
+/// the public key.
+#[derive(Clone)]
+pub struct RSAOAEPEncrypter {
+    inner: RsaPublicKey,
+}
+
+impl RSAOAEPEncrypter {
+    pub fn new(key: RsaPublicKey) -> Self {
+        RSAOAEPEncrypter {
+            inner: key,
+        }
+    }
+
+    /// Loads the public key from a PEM encoded SPKI (`PUBLIC KEY`) or PKCS#1 (`RSA PUBLIC KEY`)
+    /// key. Fails with [KeyError::KeyTypeMismatch] if the key is not an RSA key.
+    pub fn from_public_pem(pem: &str) -> Result<Self, KeyError> {
+        Ok(RSAOAEPEncrypter::new(formats::public_key_from_pem(pem)?))
+    }
+
+    /// Loads the public key from a DER encoded SPKI or PKCS#1 key.
+    pub fn from_public_der(der: &[u8]) -> Result<Self, KeyError> {
+        Ok(RSAOAEPEncrypter::new(formats::public_key_from_der(der)?))
+    }
+
+    /// Exports the public key as a PEM encoded SPKI (`PUBLIC KEY`) key.
+    pub fn to_public_pem(&self) -> Result<String, KeyError> {
+        formats::public_key_to_pem(&self.inner)
+    }
+
+    /// Returns the public key used for this algorithm.
+    pub fn key(&self) -> &RsaPublicKey {
+        &self.inner
+    }
+}
+
+impl JweEncrypter for RSAOAEPEncrypter {
+    fn alg(&self) -> &str {
+        "RSA-OAEP"
+    }
+
+    fn encrypt_key(
+        &self,
+        _header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError> {
+        let encrypted_key = rsa_oaep::encrypt(&self.inner, Oaep::new::<Sha1>(), &cek)?;
+        Ok((cek, encrypted_key))
+    }
+}
+
+impl Debug for RSAOAEPEncrypter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RSAOAEPEncrypter {{ .. }}")
+    }
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use rand_core::OsRng;
+use rsa::{Oaep, RsaPrivateKey, RsaPublicKey};
+use zeroize::Zeroizing;
+use crate::jwe::{JweError, JweHeader};
+
+/// Encrypts the content encryption key using RSAES-OAEP from
+/// [RFC 8017 section 7.1](https://www.rfc-editor.org/rfc/rfc8017#section-7.1).
+pub(crate) fn encrypt(key: &RsaPublicKey, padding: Oaep, cek: &[u8]) -> Result<Vec<u8>, JweError> {
+    key.encrypt(&mut OsRng, padding, cek)
+        .map_err(|_| JweError::InvalidKey)
+}
+
+/// Decrypts a content encryption key encrypted by [encrypt].
+///
+/// To prevent padding oracle attacks this never fails when the key cannot be decrypted. As
+/// described in [RFC 7516 section 11.5](https://www.rfc-editor.org/rfc/rfc7516#section-11.5), a
+/// random key is returned instead, so decrypting the content fails in the same way as it would with
+/// a valid key and a tampered ciphertext.
+pub(crate) fn decrypt(
+    key: &RsaPrivateKey,
+    padding: Oaep,
+    header: &JweHeader,
+    encrypted_key: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, JweError> {
+    let enc = header.content_encryption()?;
+
+    // The random key is generated up front, so both outcomes take the same steps.
+    let random_cek = enc.generate_key();
+
+    let cek = key.decrypt_blinded(&mut OsRng, padding, encrypted_key)
+        .map(Zeroizing::new);
+
+    match cek {
+        Ok(cek) if cek.len() == enc.key_len() => Ok(cek),
+        _ => Ok(random_cek),
+    }
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod jwe_encrypter;
+pub mod jwe_decrypter;
\ No newline at end of file
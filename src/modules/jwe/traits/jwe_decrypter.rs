@@ -0,0 +1,10 @@
+use zeroize::Zeroizing;
+use crate::jwe::{JweError, JweHeader};
+
+/// Decrypts the content encryption key (CEK) of a JWE token. Any failure should be reported as
+/// [JweError::DecryptionFailed], so the error doesn't reveal why the key could not be decrypted.
+pub trait JweDecrypter {
+    fn alg(&self) -> &str;
+
+    fn decrypt_key(&self, header: &JweHeader, encrypted_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, JweError>;
+}
\ No newline at end of file
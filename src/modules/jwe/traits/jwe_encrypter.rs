@@ -0,0 +1,19 @@
+use zeroize::Zeroizing;
+use crate::jwe::{JweError, JweHeader};
+
+/// Determines and encrypts the content encryption key (CEK) for the recipient of a JWE token, using
+/// one of the key management algorithms from
+/// [RFC 7518 section 4](https://www.rfc-editor.org/rfc/rfc7518#section-4).
+pub trait JweEncrypter {
+    fn alg(&self) -> &str;
+
+    /// Returns the CEK used to encrypt the content and the encrypted key that is put in the token.
+    /// `cek` is a random key for the `enc` in the header, which is ignored by algorithms that
+    /// determine the CEK themselves, like `dir`. Header parameters the algorithm needs to decrypt
+    /// the key can be added to the header.
+    fn encrypt_key(
+        &self,
+        header: &mut JweHeader,
+        cek: Zeroizing<Vec<u8>>,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), JweError>;
+}
\ No newline at end of file
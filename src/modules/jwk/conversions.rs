@@ -0,0 +1,13 @@
+#[cfg(feature = "rsa")]
+mod rsa_keys;
+
+#[cfg(any(feature = "es256", feature = "es384", feature = "es512", feature = "es256k"))]
+mod ec_keys;
+
+#[cfg(feature = "eddsa")]
+mod okp_keys;
+
+#[cfg(feature = "ecdh-es")]
+mod ecdh_keys;
+
+mod algorithms;
\ No newline at end of file
@@ -0,0 +1,311 @@
+use crate::algorithm::{AnyAlgorithm, AnyVerifier};
+use crate::jwk::{Jwk, JwkError};
+
+#[cfg(feature = "hs256")]
+use crate::algorithm::HS256Algorithm;
+
+#[cfg(feature = "hs384")]
+use crate::algorithm::HS384Algorithm;
+
+#[cfg(feature = "hs512")]
+use crate::algorithm::HS512Algorithm;
+
+#[cfg(feature = "rs256")]
+use crate::algorithm::{RS256Algorithm, RS256Verifier};
+
+#[cfg(feature = "rs384")]
+use crate::algorithm::{RS384Algorithm, RS384Verifier};
+
+#[cfg(feature = "rs512")]
+use crate::algorithm::{RS512Algorithm, RS512Verifier};
+
+#[cfg(feature = "ps256")]
+use crate::algorithm::{PS256Algorithm, PS256Verifier};
+
+#[cfg(feature = "ps384")]
+use crate::algorithm::{PS384Algorithm, PS384Verifier};
+
+#[cfg(feature = "ps512")]
+use crate::algorithm::{PS512Algorithm, PS512Verifier};
+
+#[cfg(feature = "es256")]
+use crate::algorithm::{ES256Algorithm, ES256Verifier};
+
+#[cfg(feature = "es384")]
+use crate::algorithm::{ES384Algorithm, ES384Verifier};
+
+#[cfg(feature = "es512")]
+use crate::algorithm::{ES512Algorithm, ES512Verifier};
+
+#[cfg(feature = "es256k")]
+use crate::algorithm::{ES256KAlgorithm, ES256KVerifier};
+
+#[cfg(feature = "eddsa")]
+use crate::algorithm::{EdDSAAlgorithm, EdDSAVerifier};
+
+/// Implements the conversions between a [Jwk] and an HMAC algorithm, using an `oct` key.
+#[cfg(feature = "hmac")]
+macro_rules! hmac_conversions {
+    ($algorithm:ty, $alg:literal) => {
+        impl From<&$algorithm> for Jwk {
+            fn from(algorithm: &$algorithm) -> Self {
+                Jwk::from_secret(algorithm.key())
+                    .with_alg($alg)
+            }
+        }
+
+        impl TryFrom<&Jwk> for $algorithm {
+            type Error = JwkError;
+
+            fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+                jwk.check_alg($alg)?;
+                Ok(<$algorithm>::new(&jwk.secret()?)?)
+            }
+        }
+    };
+}
+
+/// Implements the conversions between a [Jwk] and an asymmetric algorithm or verifier. `$new`
+/// creates the algorithm from the key parsed from the JWK, and `$key` returns the key that is
+/// converted into the JWK.
+#[allow(unused_macros)]
+macro_rules! key_conversions {
+    ($algorithm:ty, $alg:literal, |$key:ident: $key_ty:ty| $new:expr, |$this:ident| $to_key:expr) => {
+        impl From<&$algorithm> for Jwk {
+            fn from($this: &$algorithm) -> Self {
+                Jwk::from($to_key)
+                    .with_alg($alg)
+            }
+        }
+
+        impl TryFrom<&Jwk> for $algorithm {
+            type Error = JwkError;
+
+            fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+                jwk.check_alg($alg)?;
+
+                let $key = <$key_ty>::try_from(jwk)?;
+                Ok($new)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "hs256")]
+hmac_conversions!(HS256Algorithm, "HS256");
+
+#[cfg(feature = "hs384")]
+hmac_conversions!(HS384Algorithm, "HS384");
+
+#[cfg(feature = "hs512")]
+hmac_conversions!(HS512Algorithm, "HS512");
+
+#[cfg(feature = "rs256")]
+key_conversions!(RS256Algorithm, "RS256",
+    |key: rsa::RsaPrivateKey| RS256Algorithm::new(rsa::pkcs1v15::SigningKey::new(key)),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "rs256")]
+key_conversions!(RS256Verifier, "RS256",
+    |key: rsa::RsaPublicKey| RS256Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "rs384")]
+key_conversions!(RS384Algorithm, "RS384",
+    |key: rsa::RsaPrivateKey| RS384Algorithm::new(rsa::pkcs1v15::SigningKey::new(key)),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "rs384")]
+key_conversions!(RS384Verifier, "RS384",
+    |key: rsa::RsaPublicKey| RS384Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "rs512")]
+key_conversions!(RS512Algorithm, "RS512",
+    |key: rsa::RsaPrivateKey| RS512Algorithm::new(rsa::pkcs1v15::SigningKey::new(key)),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "rs512")]
+key_conversions!(RS512Verifier, "RS512",
+    |key: rsa::RsaPublicKey| RS512Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps256")]
+key_conversions!(PS256Algorithm, "PS256",
+    |key: rsa::RsaPrivateKey| PS256Algorithm::new(key),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps256")]
+key_conversions!(PS256Verifier, "PS256",
+    |key: rsa::RsaPublicKey| PS256Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps384")]
+key_conversions!(PS384Algorithm, "PS384",
+    |key: rsa::RsaPrivateKey| PS384Algorithm::new(key),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps384")]
+key_conversions!(PS384Verifier, "PS384",
+    |key: rsa::RsaPublicKey| PS384Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps512")]
+key_conversions!(PS512Algorithm, "PS512",
+    |key: rsa::RsaPrivateKey| PS512Algorithm::new(key),
+    |this| AsRef::<rsa::RsaPrivateKey>::as_ref(this.key()));
+
+#[cfg(feature = "ps512")]
+key_conversions!(PS512Verifier, "PS512",
+    |key: rsa::RsaPublicKey| PS512Verifier::new(key),
+    |this| AsRef::<rsa::RsaPublicKey>::as_ref(this.key()));
+
+#[cfg(feature = "es256")]
+key_conversions!(ES256Algorithm, "ES256",
+    |key: p256::ecdsa::SigningKey| ES256Algorithm::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es256")]
+key_conversions!(ES256Verifier, "ES256",
+    |key: p256::ecdsa::VerifyingKey| ES256Verifier::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es384")]
+key_conversions!(ES384Algorithm, "ES384",
+    |key: p384::ecdsa::SigningKey| ES384Algorithm::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es384")]
+key_conversions!(ES384Verifier, "ES384",
+    |key: p384::ecdsa::VerifyingKey| ES384Verifier::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es512")]
+key_conversions!(ES512Algorithm, "ES512",
+    |key: p521::ecdsa::SigningKey| ES512Algorithm::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es512")]
+key_conversions!(ES512Verifier, "ES512",
+    |key: p521::ecdsa::VerifyingKey| ES512Verifier::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es256k")]
+key_conversions!(ES256KAlgorithm, "ES256K",
+    |key: k256::ecdsa::SigningKey| ES256KAlgorithm::new(key),
+    |this| this.key());
+
+#[cfg(feature = "es256k")]
+key_conversions!(ES256KVerifier, "ES256K",
+    |key: k256::ecdsa::VerifyingKey| ES256KVerifier::new(key),
+    |this| this.key());
+
+#[cfg(feature = "eddsa")]
+key_conversions!(EdDSAAlgorithm, "EdDSA",
+    |key: ed25519_dalek::SigningKey| EdDSAAlgorithm::new(key),
+    |this| this.key());
+
+#[cfg(feature = "eddsa")]
+key_conversions!(EdDSAVerifier, "EdDSA",
+    |key: ed25519_dalek::VerifyingKey| EdDSAVerifier::new(key),
+    |this| this.key());
+
+/// Implements the conversions between a [Jwk] and [AnyAlgorithm] or [AnyVerifier]. The variant is
+/// selected using [Jwk::alg_or_default].
+macro_rules! any_conversions {
+    ($any:ident { $($(#[$meta:meta])* $variant:ident($inner:ty) => $alg:literal,)* }) => {
+        impl $any {
+            // `jwk` is unused when none of the algorithm features are enabled.
+            #[allow(unused_variables)]
+            pub(crate) fn from_jwk(jwk: &Jwk, alg: &str) -> Result<Self, JwkError> {
+                match alg {
+                    $($(#[$meta])* $alg => Ok($any::$variant(<$inner>::try_from(jwk)?)),)*
+                    _ => Err(JwkError::UnsupportedAlg),
+                }
+            }
+        }
+
+        impl TryFrom<&Jwk> for $any {
+            type Error = JwkError;
+
+            fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+                $any::from_jwk(jwk, jwk.alg_or_default()?)
+            }
+        }
+
+        /// Fails with [JwkError::UnsupportedAlg] for the `none` algorithm, as it does not have a
+        /// key.
+        impl TryFrom<&$any> for Jwk {
+            type Error = JwkError;
+
+            fn try_from(any: &$any) -> Result<Self, Self::Error> {
+                match any {
+                    $any::None(_) => Err(JwkError::UnsupportedAlg),
+                    $($(#[$meta])* $any::$variant(inner) => Ok(Jwk::from(inner)),)*
+                }
+            }
+        }
+    };
+}
+
+any_conversions!(AnyAlgorithm {
+    #[cfg(feature = "hs256")]
+    HS256(HS256Algorithm) => "HS256",
+    #[cfg(feature = "hs384")]
+    HS384(HS384Algorithm) => "HS384",
+    #[cfg(feature = "hs512")]
+    HS512(HS512Algorithm) => "HS512",
+    #[cfg(feature = "rs256")]
+    RS256(RS256Algorithm) => "RS256",
+    #[cfg(feature = "rs384")]
+    RS384(RS384Algorithm) => "RS384",
+    #[cfg(feature = "rs512")]
+    RS512(RS512Algorithm) => "RS512",
+    #[cfg(feature = "ps256")]
+    PS256(PS256Algorithm) => "PS256",
+    #[cfg(feature = "ps384")]
+    PS384(PS384Algorithm) => "PS384",
+    #[cfg(feature = "ps512")]
+    PS512(PS512Algorithm) => "PS512",
+    #[cfg(feature = "es256")]
+    ES256(ES256Algorithm) => "ES256",
+    #[cfg(feature = "es384")]
+    ES384(ES384Algorithm) => "ES384",
+    #[cfg(feature = "es512")]
+    ES512(ES512Algorithm) => "ES512",
+    #[cfg(feature = "es256k")]
+    ES256K(ES256KAlgorithm) => "ES256K",
+    #[cfg(feature = "eddsa")]
+    EdDSA(EdDSAAlgorithm) => "EdDSA",
+});
+
+any_conversions!(AnyVerifier {
+    #[cfg(feature = "hs256")]
+    HS256(HS256Algorithm) => "HS256",
+    #[cfg(feature = "hs384")]
+    HS384(HS384Algorithm) => "HS384",
+    #[cfg(feature = "hs512")]
+    HS512(HS512Algorithm) => "HS512",
+    #[cfg(feature = "rs256")]
+    RS256(RS256Verifier) => "RS256",
+    #[cfg(feature = "rs384")]
+    RS384(RS384Verifier) => "RS384",
+    #[cfg(feature = "rs512")]
+    RS512(RS512Verifier) => "RS512",
+    #[cfg(feature = "ps256")]
+    PS256(PS256Verifier) => "PS256",
+    #[cfg(feature = "ps384")]
+    PS384(PS384Verifier) => "PS384",
+    #[cfg(feature = "ps512")]
+    PS512(PS512Verifier) => "PS512",
+    #[cfg(feature = "es256")]
+    ES256(ES256Verifier) => "ES256",
+    #[cfg(feature = "es384")]
+    ES384(ES384Verifier) => "ES384",
+    #[cfg(feature = "es512")]
+    ES512(ES512Verifier) => "ES512",
+    #[cfg(feature = "es256k")]
+    ES256K(ES256KVerifier) => "ES256K",
+    #[cfg(feature = "eddsa")]
+    EdDSA(EdDSAVerifier) => "EdDSA",
+});
\ No newline at end of file
@@ -0,0 +1,105 @@
+use crate::jwk::{Jwk, JwkError, JwkParams};
+
+/// Implements the conversions between a [Jwk] and the ECDSA keys of a curve crate, which all share
+/// the same API.
+macro_rules! ec_conversions {
+    ($module:ident, $curve:ident, $crv:literal, $size:literal) => {
+        mod $module {
+            use $curve::{EncodedPoint, FieldBytes};
+            use $curve::ecdsa::{SigningKey, VerifyingKey};
+            use super::*;
+
+            fn decode_coordinate(value: &str) -> Result<FieldBytes, JwkError> {
+                let bytes = Jwk::decode(value)?;
+                if bytes.len() != $size {
+                    return Err(JwkError::InvalidKey);
+                }
+
+                Ok(FieldBytes::clone_from_slice(&bytes))
+            }
+
+            fn public_params(key: &VerifyingKey, d: Option<String>) -> JwkParams {
+                let point = key.to_encoded_point(false);
+
+                JwkParams::EC {
+                    crv: $crv.to_string(),
+                    x: Jwk::encode(point.x().expect("uncompressed point has an x coordinate")),
+                    y: Jwk::encode(point.y().expect("uncompressed point has a y coordinate")),
+                    d,
+                }
+            }
+
+            impl From<&VerifyingKey> for Jwk {
+                fn from(key: &VerifyingKey) -> Self {
+                    Jwk::new(public_params(key, None))
+                }
+            }
+
+            impl From<&SigningKey> for Jwk {
+                fn from(key: &SigningKey) -> Self {
+                    let d = Jwk::encode(key.to_bytes());
+                    Jwk::new(public_params(&VerifyingKey::from(key), Some(d)))
+                }
+            }
+
+            impl TryFrom<&Jwk> for VerifyingKey {
+                type Error = JwkError;
+
+                fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+                    let JwkParams::EC { crv, x, y, .. } = &jwk.params else {
+                        return Err(JwkError::KeyTypeMismatch);
+                    };
+
+                    if crv != $crv {
+                        return Err(JwkError::KeyTypeMismatch);
+                    }
+
+                    let point = EncodedPoint::from_affine_coordinates(
+                        &decode_coordinate(x)?,
+                        &decode_coordinate(y)?,
+                        false,
+                    );
+
+                    VerifyingKey::from_encoded_point(&point)
+                        .map_err(|_| JwkError::InvalidKey)
+                }
+            }
+
+            impl TryFrom<&Jwk> for SigningKey {
+                type Error = JwkError;
+
+                /// Fails with [JwkError::InvalidKey] when `x` and `y` are not the public key
+                /// belonging to `d`.
+                fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+                    let verifying_key = VerifyingKey::try_from(jwk)?;
+
+                    let JwkParams::EC { d: Some(d), .. } = &jwk.params else {
+                        return Err(JwkError::MissingPrivateKey);
+                    };
+
+                    let signing_key = SigningKey::from_bytes(&decode_coordinate(d)?)
+                        .map_err(|_| JwkError::InvalidKey)?;
+
+                    let derived_key = VerifyingKey::from(&signing_key);
+                    if derived_key.to_encoded_point(false) != verifying_key.to_encoded_point(false) {
+                        return Err(JwkError::InvalidKey);
+                    }
+
+                    Ok(signing_key)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "es256")]
+ec_conversions!(p256_keys, p256, "P-256", 32);
+
+#[cfg(feature = "es384")]
+ec_conversions!(p384_keys, p384, "P-384", 48);
+
+#[cfg(feature = "es512")]
+ec_conversions!(p521_keys, p521, "P-521", 66);
+
+#[cfg(feature = "es256k")]
+ec_conversions!(k256_keys, k256, "secp256k1", 32);
\ No newline at end of file
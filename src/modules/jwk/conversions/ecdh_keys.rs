@@ -0,0 +1,104 @@
+use p256::{EncodedPoint, FieldBytes};
+use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use x25519_dalek::{PublicKey, StaticSecret};
+use crate::jwe::{EcdhKey, EcdhPublicKey};
+use crate::jwk::{Jwk, JwkError, JwkParams};
+
+fn decode_bytes(value: &str) -> Result<[u8; 32], JwkError> {
+    Jwk::decode(value)?
+        .try_into()
+        .map_err(|_| JwkError::InvalidKey)
+}
+
+fn public_params(key: &EcdhPublicKey, d: Option<String>) -> JwkParams {
+    match key {
+        EcdhPublicKey::P256(key) => {
+            let point = key.to_encoded_point(false);
+
+            JwkParams::EC {
+                crv: "P-256".to_string(),
+                x: Jwk::encode(point.x().expect("uncompressed point has an x coordinate")),
+                y: Jwk::encode(point.y().expect("uncompressed point has a y coordinate")),
+                d,
+            }
+        },
+        EcdhPublicKey::X25519(key) => JwkParams::OKP {
+            crv: "X25519".to_string(),
+            x: Jwk::encode(key.as_bytes()),
+            d,
+        },
+    }
+}
+
+impl From<&EcdhPublicKey> for Jwk {
+    fn from(key: &EcdhPublicKey) -> Self {
+        Jwk::new(public_params(key, None))
+    }
+}
+
+impl From<&EcdhKey> for Jwk {
+    fn from(key: &EcdhKey) -> Self {
+        let d = match key {
+            EcdhKey::P256(key) => Jwk::encode(key.to_bytes()),
+            EcdhKey::X25519(key) => Jwk::encode(key.as_bytes()),
+        };
+
+        Jwk::new(public_params(&key.public_key(), Some(d)))
+    }
+}
+
+impl TryFrom<&Jwk> for EcdhPublicKey {
+    type Error = JwkError;
+
+    /// Fails with [JwkError::InvalidKey] when a P-256 point is not on the curve, which prevents
+    /// invalid curve attacks using the `epk` header parameter.
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        match &jwk.params {
+            JwkParams::EC { crv, x, y, .. } if crv == "P-256" => {
+                let point = EncodedPoint::from_affine_coordinates(
+                    &FieldBytes::from(decode_bytes(x)?),
+                    &FieldBytes::from(decode_bytes(y)?),
+                    false,
+                );
+
+                Option::from(p256::PublicKey::from_encoded_point(&point))
+                    .map(EcdhPublicKey::P256)
+                    .ok_or(JwkError::InvalidKey)
+            },
+            JwkParams::OKP { crv, x, .. } if crv == "X25519" => {
+                Ok(EcdhPublicKey::X25519(PublicKey::from(decode_bytes(x)?)))
+            },
+            _ => Err(JwkError::KeyTypeMismatch),
+        }
+    }
+}
+
+impl TryFrom<&Jwk> for EcdhKey {
+    type Error = JwkError;
+
+    /// Fails with [JwkError::InvalidKey] when the public key does not belong to `d`.
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let public_key = EcdhPublicKey::try_from(jwk)?;
+
+        let (JwkParams::EC { d: Some(d), .. } | JwkParams::OKP { d: Some(d), .. }) = &jwk.params else {
+            return Err(JwkError::MissingPrivateKey);
+        };
+
+        let key = match public_key {
+            EcdhPublicKey::P256(_) => {
+                let bytes = FieldBytes::from(decode_bytes(d)?);
+
+                p256::SecretKey::from_bytes(&bytes)
+                    .map(EcdhKey::P256)
+                    .map_err(|_| JwkError::InvalidKey)?
+            },
+            EcdhPublicKey::X25519(_) => EcdhKey::X25519(StaticSecret::from(decode_bytes(d)?)),
+        };
+
+        if key.public_key() != public_key {
+            return Err(JwkError::InvalidKey);
+        }
+
+        Ok(key)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use crate::jwk::{Jwk, JwkError, JwkParams};
+
+const CURVE: &str = "Ed25519";
+
+fn decode_key(value: &str) -> Result<[u8; 32], JwkError> {
+    Jwk::decode(value)?
+        .try_into()
+        .map_err(|_| JwkError::InvalidKey)
+}
+
+impl From<&VerifyingKey> for Jwk {
+    fn from(key: &VerifyingKey) -> Self {
+        Jwk::new(JwkParams::OKP {
+            crv: CURVE.to_string(),
+            x: Jwk::encode(key.as_bytes()),
+            d: None,
+        })
+    }
+}
+
+impl From<&SigningKey> for Jwk {
+    fn from(key: &SigningKey) -> Self {
+        Jwk::new(JwkParams::OKP {
+            crv: CURVE.to_string(),
+            x: Jwk::encode(key.verifying_key().as_bytes()),
+            d: Some(Jwk::encode(key.as_bytes())),
+        })
+    }
+}
+
+impl TryFrom<&Jwk> for VerifyingKey {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let JwkParams::OKP { crv, x, .. } = &jwk.params else {
+            return Err(JwkError::KeyTypeMismatch);
+        };
+
+        if crv != CURVE {
+            return Err(JwkError::KeyTypeMismatch);
+        }
+
+        VerifyingKey::from_bytes(&decode_key(x)?)
+            .map_err(|_| JwkError::InvalidKey)
+    }
+}
+
+impl TryFrom<&Jwk> for SigningKey {
+    type Error = JwkError;
+
+    /// Fails with [JwkError::InvalidKey] when `x` is not the public key belonging to `d`.
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let verifying_key = VerifyingKey::try_from(jwk)?;
+
+        let JwkParams::OKP { d: Some(d), .. } = &jwk.params else {
+            return Err(JwkError::MissingPrivateKey);
+        };
+
+        let signing_key = SigningKey::from_bytes(&decode_key(d)?);
+        if signing_key.verifying_key() != verifying_key {
+            return Err(JwkError::InvalidKey);
+        }
+
+        Ok(signing_key)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey};
+use rsa::traits::{PrivateKeyParts, PublicKeyParts};
+use crate::algorithm::formats::MIN_RSA_KEY_BITS;
+use crate::jwk::{Jwk, JwkError, JwkParams};
+
+fn encode(value: &BigUint) -> String {
+    Jwk::encode(value.to_bytes_be())
+}
+
+fn decode(value: &str) -> Result<BigUint, JwkError> {
+    Ok(BigUint::from_bytes_be(&Jwk::decode(value)?))
+}
+
+/// Decodes the modulus, failing with [JwkError::KeyTooSmall] if it is smaller than 2048 bits.
+fn decode_modulus(value: &str) -> Result<BigUint, JwkError> {
+    let n = decode(value)?;
+
+    if n.bits() < MIN_RSA_KEY_BITS {
+        return Err(JwkError::KeyTooSmall(n.bits()));
+    }
+
+    Ok(n)
+}
+
+impl From<&RsaPublicKey> for Jwk {
+    fn from(key: &RsaPublicKey) -> Self {
+        Jwk::new(JwkParams::RSA {
+            n: encode(key.n()),
+            e: encode(key.e()),
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+        })
+    }
+}
+
+impl From<&RsaPrivateKey> for Jwk {
+    fn from(key: &RsaPrivateKey) -> Self {
+        let primes = key.primes();
+
+        Jwk::new(JwkParams::RSA {
+            n: encode(key.n()),
+            e: encode(key.e()),
+            d: Some(encode(key.d())),
+            p: primes.first().map(encode),
+            q: primes.get(1).map(encode),
+            dp: key.dp().map(encode),
+            dq: key.dq().map(encode),
+            qi: key.crt_coefficient().as_ref().map(encode),
+        })
+    }
+}
+
+impl TryFrom<&Jwk> for RsaPublicKey {
+    type Error = JwkError;
+
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let JwkParams::RSA { n, e, .. } = &jwk.params else {
+            return Err(JwkError::KeyTypeMismatch);
+        };
+
+        RsaPublicKey::new(decode_modulus(n)?, decode(e)?)
+            .map_err(|_| JwkError::InvalidKey)
+    }
+}
+
+impl TryFrom<&Jwk> for RsaPrivateKey {
+    type Error = JwkError;
+
+    /// The CRT parameters are recomputed from the primes. When the primes are missing they are
+    /// recovered from `n`, `e` and `d`.
+    fn try_from(jwk: &Jwk) -> Result<Self, Self::Error> {
+        let JwkParams::RSA { n, e, d, p, q, .. } = &jwk.params else {
+            return Err(JwkError::KeyTypeMismatch);
+        };
+
+        let d = d.as_ref()
+            .ok_or(JwkError::MissingPrivateKey)?;
+
+        let primes = match (p, q) {
+            (Some(p), Some(q)) => vec![decode(p)?, decode(q)?],
+            _ => vec![],
+        };
+
+        RsaPrivateKey::from_components(decode_modulus(n)?, decode(e)?, decode(d)?, primes)
+            .map_err(|_| JwkError::InvalidKey)
+    }
+}
\ No newline at end of file
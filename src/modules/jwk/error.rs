@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub enum JwkError {
+    DecodeError(#[from] base64::DecodeError),
+
+    #[cfg(feature = "hmac")]
+    HmacKeyError(#[from] crate::algorithm::HmacKeyError),
+
+    #[error("The `kty` or `crv` of the JWK does not match the expected key type")]
+    KeyTypeMismatch,
+
+    #[error("The `alg` of the JWK does not match the algorithm")]
+    AlgMismatch,
+
+    #[error("The `alg` of the JWK is not supported")]
+    UnsupportedAlg,
+
+    #[error("The JWK does not specify an `alg` and it cannot be derived from the key")]
+    MissingAlg,
+
+    #[error("The JWK does not contain a private key")]
+    MissingPrivateKey,
+
+    #[error("The JWK does not contain a valid key")]
+    InvalidKey,
+
+    /// RSA keys must be at least 2048 bits as required by
+    /// [RFC 7518 section 3.3](https://www.rfc-editor.org/rfc/rfc7518#section-3.3).
+    #[cfg(feature = "rsa")]
+    #[error("RSA keys must be at least 2048 bits, got {0} bits")]
+    KeyTooSmall(usize),
+}
\ No newline at end of file
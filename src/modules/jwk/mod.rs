@@ -0,0 +1,455 @@
+mod models;
+mod conversions;
+mod error;
+
+pub use models::jwk::Jwk;
+pub use models::jwk_params::JwkParams;
+pub use models::jwk_set::JwkSet;
+pub use error::JwkError;
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+    use sha2::Digest;
+    use crate::jwk::{Jwk, JwkSet};
+
+    #[cfg(feature = "hs256")]
+    use crate::algorithm::{HS256Algorithm, JwSigner, JwVerifier};
+
+    #[cfg(feature = "hs256")]
+    use crate::jwk::JwkError;
+
+    #[cfg(feature = "hs256")]
+    use crate::token::{Jwt, JwtError, JwtHeader};
+
+    /// Creates a token with the given `kid` in the header, signed using the given algorithm.
+    #[cfg(feature = "hs256")]
+    fn token_with_kid(algorithm: &impl JwSigner, kid: &str) -> String {
+        Jwt::new("hello world".to_string())
+            .with_header(JwtHeader::default().with_kid(kid))
+            .into_token(algorithm)
+            .unwrap()
+    }
+
+    /// The symmetric key from
+    /// [RFC 7517 appendix A.3](https://www.rfc-editor.org/rfc/rfc7517#appendix-A.3), which is also
+    /// used to sign the example in
+    /// [RFC 7515 appendix A.1](https://www.rfc-editor.org/rfc/rfc7515#appendix-A.1).
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn hs256_algorithm_can_be_created_from_a_jwk() {
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "oct",
+            "k": "AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow",
+            "kid": "HMAC key used in JWS spec Appendix A.1 example"
+        }"#).unwrap();
+
+        assert_eq!(jwk.kid.as_deref(), Some("HMAC key used in JWS spec Appendix A.1 example"));
+
+        let algorithm = HS256Algorithm::try_from(&jwk)
+            .unwrap();
+
+        let payload = "eyJ0eXAiOiJKV1QiLA0KICJhbGciOiJIUzI1NiJ9.eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ";
+        let signature = BASE64_URL_SAFE_NO_PAD.decode("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk").unwrap();
+
+        assert!(algorithm.verify(payload, &signature).unwrap());
+
+        let converted = Jwk::from(&algorithm);
+
+        assert_eq!(converted.params, jwk.params);
+        assert_eq!(converted.alg.as_deref(), Some("HS256"));
+    }
+
+    /// The public keys from
+    /// [RFC 7517 appendix A.1](https://www.rfc-editor.org/rfc/rfc7517#appendix-A.1).
+    #[test]
+    fn jwk_can_be_round_tripped_using_serde() {
+        let ec = r#"{"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM","use":"enc","kid":"1"}"#;
+        let rsa = r#"{"kty":"RSA","n":"0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw","e":"AQAB","alg":"RS256","kid":"2011-04-29"}"#;
+
+        for json in [ec, rsa] {
+            let jwk: Jwk = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&jwk).unwrap(), json);
+        }
+
+        let jwk: Jwk = serde_json::from_str(ec).unwrap();
+
+        assert_eq!(jwk.kty(), "EC");
+        assert_eq!(jwk.key_use.as_deref(), Some("enc"));
+        assert_eq!(jwk.alg_or_default().unwrap(), "ES256");
+
+        let jwk = Jwk::from_secret(b"secret")
+            .with_use("sig")
+            .with_key_ops(["sign", "verify"])
+            .with_alg("HS256")
+            .with_kid("key-1");
+
+        let json = serde_json::to_string(&jwk).unwrap();
+
+        assert_eq!(json, r#"{"kty":"oct","k":"c2VjcmV0","use":"sig","key_ops":["sign","verify"],"alg":"HS256","kid":"key-1"}"#);
+        assert_eq!(serde_json::from_str::<Jwk>(&json).unwrap(), jwk);
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn jwk_with_incorrect_alg_is_rejected() {
+        let jwk = Jwk::from_secret(b"secret")
+            .with_alg("HS512");
+
+        assert!(matches!(HS256Algorithm::try_from(&jwk), Err(JwkError::AlgMismatch)));
+        assert!(matches!(Jwk::from_secret(b"secret").algorithm(), Err(JwkError::MissingAlg)));
+    }
+
+    /// The public key and signature from
+    /// [RFC 7515 appendix A.3](https://www.rfc-editor.org/rfc/rfc7515#appendix-A.3).
+    #[test]
+    #[cfg(feature = "es256")]
+    fn es256_verifier_can_be_created_from_a_jwk() {
+        use crate::algorithm::{AnyVerifier, ES256Algorithm, JwVerifier};
+        use crate::jwk::JwkError;
+
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "EC",
+            "crv": "P-256",
+            "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+            "y": "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"
+        }"#).unwrap();
+
+        let verifier = jwk.verifier()
+            .unwrap();
+
+        assert!(matches!(verifier, AnyVerifier::ES256(_)));
+
+        let payload = "eyJhbGciOiJFUzI1NiJ9.eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ";
+        let signature = BASE64_URL_SAFE_NO_PAD.decode("DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q").unwrap();
+
+        assert!(verifier.verify(payload, &signature).unwrap());
+        assert!(matches!(ES256Algorithm::try_from(&jwk), Err(JwkError::MissingPrivateKey)));
+    }
+
+    #[test]
+    #[cfg(feature = "es256")]
+    fn es256_algorithm_can_be_converted_to_and_from_a_jwk() {
+        use p256::ecdsa::SigningKey;
+        use p256::SecretKey;
+        use crate::algorithm::{AnyAlgorithm, ES256Algorithm, ES256Verifier, JwSigner, JwVerifier};
+        use crate::jwk::{JwkError, JwkParams};
+
+        let secret_key = include_str!("../../../test-files/es256.key").parse::<SecretKey>().unwrap();
+        let algorithm = ES256Algorithm::new(SigningKey::from(secret_key));
+
+        let jwk = Jwk::from(&algorithm);
+
+        assert!(matches!(jwk.algorithm(), Ok(AnyAlgorithm::ES256(_))));
+
+        let converted = ES256Algorithm::try_from(&jwk)
+            .unwrap();
+
+        assert_eq!(converted.key(), algorithm.key());
+
+        let public_jwk = Jwk::from(&algorithm.verifier());
+        let verifier = ES256Verifier::try_from(&public_jwk)
+            .unwrap();
+
+        let payload = "hello world";
+
+        assert!(verifier.verify(payload, &algorithm.sign(payload)).unwrap());
+
+        let mut mismatched = jwk.clone();
+        if let JwkParams::EC { x, y, .. } = &mut mismatched.params {
+            *x = "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU".to_string();
+            *y = "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0".to_string();
+        }
+
+        assert!(matches!(ES256Algorithm::try_from(&mismatched), Err(JwkError::InvalidKey)));
+
+        let mut wrong_curve = Jwk::new(jwk.params.clone());
+        if let JwkParams::EC { crv, .. } = &mut wrong_curve.params {
+            *crv = "P-384".to_string();
+        }
+
+        assert!(matches!(ES256Algorithm::try_from(&wrong_curve), Err(JwkError::KeyTypeMismatch)));
+    }
+
+    #[test]
+    #[cfg(feature = "rs256")]
+    fn rs256_algorithm_can_be_converted_to_and_from_a_jwk() {
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        use rsa::pkcs1v15::SigningKey;
+        use rsa::RsaPrivateKey;
+        use crate::algorithm::{AnyVerifier, JwSigner, JwVerifier, RS256Algorithm, RS256Verifier};
+        use crate::jwk::{JwkError, JwkParams};
+
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../test-files/rs256.key")).unwrap();
+        let algorithm = RS256Algorithm::new(SigningKey::new(private_key));
+
+        let jwk = Jwk::from(&algorithm);
+        let json = serde_json::to_string(&jwk).unwrap();
+        let jwk: Jwk = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(jwk.alg.as_deref(), Some("RS256"));
+
+        let converted = RS256Algorithm::try_from(&jwk)
+            .unwrap();
+
+        let payload = "hello world";
+        let signature = algorithm.sign(payload);
+
+        assert_eq!(converted.sign(payload), signature);
+
+        let public_jwk = Jwk::from(&algorithm.verifier());
+
+        assert!(matches!(public_jwk.params, JwkParams::RSA { d: None, .. }));
+        assert!(matches!(public_jwk.verifier(), Ok(AnyVerifier::RS256(_))));
+        assert!(RS256Verifier::try_from(&public_jwk).unwrap().verify(payload, &signature).unwrap());
+        assert!(matches!(RS256Algorithm::try_from(&public_jwk), Err(JwkError::MissingPrivateKey)));
+
+        #[cfg(feature = "hs256")]
+        {
+            assert!(matches!(HS256Algorithm::try_from(&public_jwk), Err(JwkError::AlgMismatch)));
+            assert!(matches!(HS256Algorithm::try_from(&Jwk::new(public_jwk.params)), Err(JwkError::KeyTypeMismatch)));
+        }
+
+        let small_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../test-files/rs1024.key")).unwrap();
+        let small_jwk = Jwk::from(&small_key);
+
+        assert!(matches!(RsaPrivateKey::try_from(&small_jwk), Err(JwkError::KeyTooSmall(1024))));
+        assert!(matches!(RS256Verifier::try_from(&small_jwk), Err(JwkError::KeyTooSmall(1024))));
+    }
+
+    /// The key from [RFC 8037 appendix A.1](https://www.rfc-editor.org/rfc/rfc8037#appendix-A.1).
+    #[test]
+    #[cfg(feature = "eddsa")]
+    fn eddsa_algorithm_can_be_created_from_a_jwk() {
+        use crate::algorithm::EdDSAAlgorithm;
+
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+            "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }"#).unwrap();
+
+        let algorithm = EdDSAAlgorithm::try_from(&jwk)
+            .unwrap();
+
+        let expected = EdDSAAlgorithm::from_pem(include_str!("../../../test-files/eddsa.key")).unwrap();
+
+        assert_eq!(algorithm.key().to_bytes(), expected.key().to_bytes());
+        assert_eq!(Jwk::from(&expected).alg_or_default().unwrap(), "EdDSA");
+    }
+
+    /// The public keys from
+    /// [RFC 7517 appendix A.1](https://www.rfc-editor.org/rfc/rfc7517#appendix-A.1).
+    #[test]
+    fn jwk_set_can_be_loaded_from_json() {
+        let jwks: JwkSet = serde_json::from_str(r#"{"keys": [
+            {"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM","use":"enc","kid":"1"},
+            {"kty":"RSA","n":"0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw","e":"AQAB","alg":"RS256","kid":"2011-04-29"}
+        ]}"#).unwrap();
+
+        assert_eq!(jwks.keys.len(), 2);
+        assert_eq!(jwks.find("1").unwrap().kty(), "EC");
+        assert_eq!(jwks.find("2011-04-29").unwrap().kty(), "RSA");
+        assert!(jwks.find("2").is_none());
+
+        let json = serde_json::to_string(&jwks).unwrap();
+
+        assert_eq!(serde_json::from_str::<JwkSet>(&json).unwrap(), jwks);
+    }
+
+    #[test]
+    fn jwk_set_skips_keys_that_cannot_be_parsed() {
+        let jwks: JwkSet = serde_json::from_str(r#"{"keys": [
+            {"kty":"unknown","kid":"1"},
+            {"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","kid":"2"},
+            {"kty":"oct","k":"c2VjcmV0","alg":"HS256","kid":"3"}
+        ]}"#).unwrap();
+
+        assert_eq!(jwks.keys.len(), 1);
+        assert_eq!(jwks.find("3").unwrap().kty(), "oct");
+
+        assert!(serde_json::from_str::<JwkSet>(r#"{"keys": {}}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn jwk_set_selects_the_key_using_the_kid() {
+        let algorithm_a = HS256Algorithm::new(b"secret a").unwrap();
+        let algorithm_b = HS256Algorithm::new(b"secret b").unwrap();
+
+        let jwks = JwkSet::new(vec![
+            Jwk::from(&algorithm_a).with_kid("a"),
+            Jwk::from(&algorithm_b).with_kid("b"),
+        ]);
+
+        let jwt = Jwt::<String>::check(&token_with_kid(&algorithm_b, "b"), &jwks)
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+
+        let result = Jwt::<String>::check(&token_with_kid(&algorithm_b, "a"), &jwks);
+        assert!(matches!(result, Err(JwtError::InvalidSignature)));
+
+        let result = Jwt::<String>::check(&token_with_kid(&algorithm_b, "c"), &jwks);
+        assert!(matches!(result, Err(JwtError::NoMatchingKey)));
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm_b)
+            .unwrap();
+
+        let result = Jwt::<String>::check(&token, &jwks);
+        assert!(matches!(result, Err(JwtError::AmbiguousKey)));
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm_a)
+            .unwrap();
+
+        let result = Jwt::<String>::check(&token, &JwkSet::new(vec![Jwk::from(&algorithm_a)]));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn jwk_set_selects_the_key_using_the_alg_and_use() {
+        let algorithm = HS256Algorithm::new(b"secret").unwrap();
+
+        let jwks = JwkSet::new(vec![
+            Jwk::from_secret(b"other").with_kid("key").with_alg("HS512"),
+            Jwk::from_secret(b"other").with_kid("key").with_use("enc"),
+            Jwk::from_secret(b"other").with_kid("key").with_key_ops(["sign"]),
+            Jwk::from_secret(b"secret").with_kid("key").with_use("sig").with_key_ops(["verify"]),
+        ]);
+
+        let token = token_with_kid(&algorithm, "key");
+
+        assert!(Jwt::<String>::check(&token, &jwks).is_ok());
+
+        let jwks = JwkSet::new(vec![
+            Jwk::from_secret(b"secret").with_alg("HS512"),
+        ]);
+
+        let result = Jwt::<String>::check(&token, &jwks);
+        assert!(matches!(result, Err(JwtError::NoMatchingKey)));
+    }
+
+    #[test]
+    #[cfg(feature = "es256")]
+    fn jwk_set_only_selects_keys_of_the_correct_type() {
+        use p256::ecdsa::SigningKey;
+        use p256::SecretKey;
+        use crate::algorithm::ES256Algorithm;
+        use crate::token::Jwt;
+
+        let secret_key = include_str!("../../../test-files/es256.key").parse::<SecretKey>().unwrap();
+        let algorithm = ES256Algorithm::new(SigningKey::from(secret_key));
+
+        let jwks = JwkSet::new(vec![
+            Jwk::from_secret(b"secret"),
+            Jwk::new(Jwk::from(&algorithm.verifier()).params),
+        ]);
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        assert!(Jwt::<String>::check(&token, &jwks).is_ok());
+    }
+
+    /// The example from [RFC 7638 section 3.1](https://www.rfc-editor.org/rfc/rfc7638#section-3.1)
+    /// and the URI from [RFC 9278 section 3](https://www.rfc-editor.org/rfc/rfc9278#section-3).
+    #[test]
+    fn rsa_jwk_thumbprint_matches_rfc_7638() {
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "RSA",
+            "n": "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw",
+            "e": "AQAB",
+            "alg": "RS256",
+            "kid": "2011-04-29"
+        }"#).unwrap();
+
+        assert_eq!(jwk.thumbprint(), "NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs");
+        assert_eq!(jwk.thumbprint_uri(), "urn:ietf:params:oauth:jwk-thumbprint:sha-256:NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs");
+    }
+
+    /// The example from [RFC 8037 appendix A.3](https://www.rfc-editor.org/rfc/rfc8037#appendix-A.3),
+    /// where the private key must result in the same thumbprint as the public key.
+    #[test]
+    fn okp_jwk_thumbprint_matches_rfc_8037() {
+        let public_jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }"#).unwrap();
+
+        let private_jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+            "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+            "kid": "some-key"
+        }"#).unwrap();
+
+        assert_eq!(public_jwk.thumbprint(), "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k");
+        assert_eq!(private_jwk.thumbprint(), public_jwk.thumbprint());
+    }
+
+    #[test]
+    fn debug_output_redacts_the_private_key_parameters() {
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+            "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+            "kid": "some-key"
+        }"#).unwrap();
+
+        assert_eq!(
+            format!("{:?}", jwk),
+            r#"Jwk { params: OKP { crv: "Ed25519", x: "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo", d: Some(..) }, key_use: None, key_ops: None, alg: None, kid: Some("some-key") }"#,
+        );
+
+        let jwk = Jwk::from_secret(b"secret");
+
+        assert!(!format!("{:?}", jwk).contains("c2VjcmV0"));
+    }
+
+    #[test]
+    fn ec_and_oct_jwk_thumbprints_use_the_required_members() {
+        let jwk: Jwk = serde_json::from_str(r#"{
+            "kty": "EC",
+            "crv": "P-256",
+            "x": "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+            "y": "4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM",
+            "use": "enc",
+            "kid": "1"
+        }"#).unwrap();
+
+        let members = r#"{"crv":"P-256","kty":"EC","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"}"#;
+        assert_eq!(jwk.thumbprint(), BASE64_URL_SAFE_NO_PAD.encode(sha2::Sha256::digest(members)));
+
+        let jwk = Jwk::from_secret(b"secret").with_alg("HS256");
+
+        let members = r#"{"k":"c2VjcmV0","kty":"oct"}"#;
+        assert_eq!(jwk.thumbprint(), BASE64_URL_SAFE_NO_PAD.encode(sha2::Sha256::digest(members)));
+    }
+
+    #[test]
+    #[cfg(feature = "es256")]
+    fn generated_keys_can_be_exported_as_a_jwk() {
+        use crate::algorithm::{ES256Algorithm, JwSigner, JwVerifier};
+
+        let payload = "eyJhbGciOiJFUzI1NiIsInR5cCI6IkpXVCJ9.eyJoaiI6dHJ1ZX0";
+        let alg = ES256Algorithm::generate();
+
+        let jwk = Jwk::from(&alg);
+        let jwk = jwk.clone().with_kid(jwk.thumbprint());
+
+        let json = serde_json::to_string(&jwk).unwrap();
+        let loaded: Jwk = serde_json::from_str(&json).unwrap();
+
+        let restored = ES256Algorithm::try_from(&loaded).unwrap();
+        assert!(restored.verify(payload, &alg.sign(payload)).unwrap());
+    }
+}
\ No newline at end of file
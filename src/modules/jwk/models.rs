@@ -0,0 +1,3 @@
+pub mod jwk;
+pub mod jwk_params;
+pub mod jwk_set;
\ No newline at end of file
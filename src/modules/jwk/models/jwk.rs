@@ -0,0 +1,237 @@
+use std::fmt::{Debug, Formatter};
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use crate::algorithm::{AnyAlgorithm, AnyVerifier};
+use crate::jwk::{JwkError, JwkParams};
+
+/// Prefix for SHA-256 thumbprint URIs as defined in
+/// [RFC 9278 section 3](https://www.rfc-editor.org/rfc/rfc9278#section-3).
+const THUMBPRINT_URI_PREFIX: &str = "urn:ietf:params:oauth:jwk-thumbprint:sha-256:";
+
+/// JSON Web Key as defined in [RFC 7517](https://www.rfc-editor.org/rfc/rfc7517). The key material
+/// itself is stored in [JwkParams], which depends on the `kty` of the key.
+///
+/// Keys can be converted from and to the key types used by the algorithms, or directly from and
+/// to the algorithms. When converting from an algorithm the `alg` parameter is set.
+///
+/// The [Debug] output does not contain the private key parameters.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Jwk {
+    #[serde(flatten)]
+    pub params: JwkParams,
+
+    /// From [RFC 7517 section 4.2](https://www.rfc-editor.org/rfc/rfc7517#section-4.2):
+    /// The "use" (public key use) parameter identifies the intended use of the public key. Values
+    /// defined by the specification are "sig" (signature) and "enc" (encryption).
+    #[serde(rename = "use", skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+
+    /// From [RFC 7517 section 4.3](https://www.rfc-editor.org/rfc/rfc7517#section-4.3):
+    /// The "key_ops" (key operations) parameter identifies the operation(s) for which the key is
+    /// intended to be used, for example "sign" or "verify".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_ops: Option<Vec<String>>,
+
+    /// From [RFC 7517 section 4.4](https://www.rfc-editor.org/rfc/rfc7517#section-4.4):
+    /// The "alg" (algorithm) parameter identifies the algorithm intended for use with the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    /// From [RFC 7517 section 4.5](https://www.rfc-editor.org/rfc/rfc7517#section-4.5):
+    /// The "kid" (key ID) parameter is used to match a specific key. This is used, for instance,
+    /// to choose among a set of keys within a JWK Set during key rollover.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+impl Debug for Jwk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Jwk")
+            .field("params", &self.params)
+            .field("key_use", &self.key_use)
+            .field("key_ops", &self.key_ops)
+            .field("alg", &self.alg)
+            .field("kid", &self.kid)
+            .finish()
+    }
+}
+
+impl Jwk {
+    pub fn new(params: JwkParams) -> Self {
+        Jwk {
+            params,
+            key_use: None,
+            key_ops: None,
+            alg: None,
+            kid: None,
+        }
+    }
+
+    /// Creates a symmetric (`oct`) key with the given secret.
+    pub fn from_secret(secret: &[u8]) -> Self {
+        Jwk::new(JwkParams::Oct {
+            k: Jwk::encode(secret),
+        })
+    }
+
+    /// Returns the secret of a symmetric (`oct`) key.
+    pub fn secret(&self) -> Result<Vec<u8>, JwkError> {
+        let JwkParams::Oct { k } = &self.params else {
+            return Err(JwkError::KeyTypeMismatch);
+        };
+
+        Jwk::decode(k)
+    }
+
+    /// Sets the `use` parameter on the JWK.
+    pub fn with_use(mut self, key_use: impl Into<String>) -> Self {
+        self.key_use = Some(key_use.into());
+        self
+    }
+
+    /// Sets the `key_ops` parameter on the JWK.
+    pub fn with_key_ops<I, S>(mut self, key_ops: I) -> Self
+    where I : IntoIterator<Item = S>,
+          S : Into<String>,
+    {
+        self.key_ops = Some(key_ops.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the `alg` parameter on the JWK.
+    pub fn with_alg(mut self, alg: impl Into<String>) -> Self {
+        self.alg = Some(alg.into());
+        self
+    }
+
+    /// Sets the `kid` parameter on the JWK.
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+
+    /// Returns the `kty` parameter of the JWK.
+    pub fn kty(&self) -> &str {
+        match &self.params {
+            JwkParams::EC { .. } => "EC",
+            JwkParams::RSA { .. } => "RSA",
+            JwkParams::Oct { .. } => "oct",
+            JwkParams::OKP { .. } => "OKP",
+        }
+    }
+
+    /// Returns the SHA-256 thumbprint of the JWK as described in
+    /// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638), encoded using base64url. Only the
+    /// required public members of the key are hashed, so the private key and public key have the
+    /// same thumbprint.
+    pub fn thumbprint(&self) -> String {
+        let string = |value: &str| Value::from(value).to_string();
+
+        // The required members in lexicographic order, without any whitespace.
+        let members = match &self.params {
+            JwkParams::EC { crv, x, y, .. } => format!(
+                r#"{{"crv":{},"kty":"EC","x":{},"y":{}}}"#,
+                string(crv), string(x), string(y),
+            ),
+            JwkParams::RSA { n, e, .. } => format!(
+                r#"{{"e":{},"kty":"RSA","n":{}}}"#,
+                string(e), string(n),
+            ),
+            JwkParams::Oct { k } => format!(
+                r#"{{"k":{},"kty":"oct"}}"#,
+                string(k),
+            ),
+            JwkParams::OKP { crv, x, .. } => format!(
+                r#"{{"crv":{},"kty":"OKP","x":{}}}"#,
+                string(crv), string(x),
+            ),
+        };
+
+        Jwk::encode(Sha256::digest(members.as_bytes()))
+    }
+
+    /// Returns the SHA-256 thumbprint URI of the JWK as described in
+    /// [RFC 9278](https://www.rfc-editor.org/rfc/rfc9278).
+    pub fn thumbprint_uri(&self) -> String {
+        format!("{}{}", THUMBPRINT_URI_PREFIX, self.thumbprint())
+    }
+
+    /// Returns the `alg` parameter of the JWK, or derives it from the curve for EC and OKP keys.
+    /// RSA and symmetric keys can be used with multiple algorithms, so they need to specify `alg`.
+    pub fn alg_or_default(&self) -> Result<&str, JwkError> {
+        if let Some(alg) = &self.alg {
+            return Ok(alg);
+        }
+
+        let alg = match &self.params {
+            JwkParams::EC { crv, .. } => match crv.as_str() {
+                "P-256" => "ES256",
+                "P-384" => "ES384",
+                "P-521" => "ES512",
+                "secp256k1" => "ES256K",
+                _ => return Err(JwkError::MissingAlg),
+            },
+            JwkParams::OKP { crv, .. } if crv == "Ed25519" => "EdDSA",
+            _ => return Err(JwkError::MissingAlg),
+        };
+
+        Ok(alg)
+    }
+
+    /// Creates the signing algorithm for this key, using [Jwk::alg_or_default] to determine which
+    /// algorithm to use. The JWK must contain the private key.
+    pub fn algorithm(&self) -> Result<AnyAlgorithm, JwkError> {
+        AnyAlgorithm::try_from(self)
+    }
+
+    /// Creates the verifier for this key, using [Jwk::alg_or_default] to determine which algorithm
+    /// to use. Only the public key is required.
+    pub fn verifier(&self) -> Result<AnyVerifier, JwkError> {
+        AnyVerifier::try_from(self)
+    }
+
+    /// Creates the signing algorithm for this key using the given algorithm, for RSA and symmetric
+    /// keys that don't specify an `alg`. Fails if the JWK specifies a different `alg`.
+    pub fn algorithm_for(&self, alg: &str) -> Result<AnyAlgorithm, JwkError> {
+        AnyAlgorithm::from_jwk(self, alg)
+    }
+
+    /// Creates the verifier for this key using the given algorithm, for RSA and symmetric keys that
+    /// don't specify an `alg`. Fails if the JWK specifies a different `alg`.
+    pub fn verifier_for(&self, alg: &str) -> Result<AnyVerifier, JwkError> {
+        AnyVerifier::from_jwk(self, alg)
+    }
+
+    /// Returns whether the key type (and curve) of the JWK can be used with the given algorithm.
+    /// This does not look at the `alg` parameter, see [Jwk::check_alg] for that.
+    pub fn supports_alg(&self, alg: &str) -> bool {
+        match &self.params {
+            JwkParams::EC { crv, .. } => matches!(
+                (alg, crv.as_str()),
+                ("ES256", "P-256") | ("ES384", "P-384") | ("ES512", "P-521") | ("ES256K", "secp256k1")
+            ),
+            JwkParams::RSA { .. } => matches!(alg, "RS256" | "RS384" | "RS512" | "PS256" | "PS384" | "PS512"),
+            JwkParams::Oct { .. } => matches!(alg, "HS256" | "HS384" | "HS512"),
+            JwkParams::OKP { crv, .. } => alg == "EdDSA" && crv == "Ed25519",
+        }
+    }
+
+    /// Returns [JwkError::AlgMismatch] when the JWK specifies an `alg` other than the given one.
+    pub fn check_alg(&self, alg: &str) -> Result<(), JwkError> {
+        match &self.alg {
+            Some(own) if own != alg => Err(JwkError::AlgMismatch),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn decode(value: &str) -> Result<Vec<u8>, JwkError> {
+        Ok(BASE64_URL_SAFE_NO_PAD.decode(value.as_bytes())?)
+    }
+
+    pub(crate) fn encode(value: impl AsRef<[u8]>) -> String {
+        BASE64_URL_SAFE_NO_PAD.encode(value)
+    }
+}
\ No newline at end of file
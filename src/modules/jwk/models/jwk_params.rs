@@ -0,0 +1,111 @@
+use std::fmt::{Debug, Formatter};
+use serde::{Deserialize, Serialize};
+
+/// The key type specific parameters of a [Jwk](crate::jwk::Jwk), tagged using the `kty` parameter.
+/// All binary values are base64url encoded without padding, as they appear in the JSON.
+///
+/// The [Debug] output only contains the public parameters, private parameters are redacted.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kty")]
+pub enum JwkParams {
+    /// Elliptic curve key as described in
+    /// [RFC 7518 section 6.2](https://www.rfc-editor.org/rfc/rfc7518#section-6.2). The private key
+    /// `d` is only set for private keys.
+    EC {
+        crv: String,
+        x: String,
+        y: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        d: Option<String>,
+    },
+
+    /// RSA key as described in
+    /// [RFC 7518 section 6.3](https://www.rfc-editor.org/rfc/rfc7518#section-6.3). The private
+    /// parameters are only set for private keys. Keys with more than two primes (`oth`) are not
+    /// supported.
+    RSA {
+        n: String,
+        e: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        d: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        p: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        q: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dp: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dq: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        qi: Option<String>,
+    },
+
+    /// Symmetric key as described in
+    /// [RFC 7518 section 6.4](https://www.rfc-editor.org/rfc/rfc7518#section-6.4).
+    #[serde(rename = "oct")]
+    Oct {
+        k: String,
+    },
+
+    /// Octet key pair as described in
+    /// [RFC 8037 section 2](https://www.rfc-editor.org/rfc/rfc8037#section-2), used for Ed25519
+    /// keys. The private key `d` is only set for private keys.
+    OKP {
+        crv: String,
+        x: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        d: Option<String>,
+    },
+}
+
+impl Debug for JwkParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwkParams::EC { crv, x, y, d } => f.debug_struct("EC")
+                .field("crv", crv)
+                .field("x", x)
+                .field("y", y)
+                .field("d", &redact(d))
+                .finish(),
+            JwkParams::RSA { n, e, d, p, q, dp, dq, qi } => f.debug_struct("RSA")
+                .field("n", n)
+                .field("e", e)
+                .field("d", &redact(d))
+                .field("p", &redact(p))
+                .field("q", &redact(q))
+                .field("dp", &redact(dp))
+                .field("dq", &redact(dq))
+                .field("qi", &redact(qi))
+                .finish(),
+            JwkParams::Oct { .. } => f.debug_struct("Oct")
+                .field("k", &Redacted)
+                .finish(),
+            JwkParams::OKP { crv, x, d } => f.debug_struct("OKP")
+                .field("crv", crv)
+                .field("x", x)
+                .field("d", &redact(d))
+                .finish(),
+        }
+    }
+}
+
+/// Placeholder for a private parameter in the [Debug] output of [JwkParams].
+struct Redacted;
+
+impl Debug for Redacted {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "..")
+    }
+}
+
+fn redact(value: &Option<String>) -> Option<Redacted> {
+    value.as_ref().map(|_| Redacted)
+}
\ No newline at end of file
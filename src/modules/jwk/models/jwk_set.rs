@@ -0,0 +1,113 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use crate::jwk::Jwk;
+use crate::token::{JwKeyResolver, JwtError, JwtHeader};
+
+/// JWK Set as defined in [RFC 7517 section 5](https://www.rfc-editor.org/rfc/rfc7517#section-5),
+/// which is the `{"keys": [...]}` document usually published by an issuer.
+///
+/// The set can be passed to [Jwt::check](crate::token::Jwt::check) directly, in which case the key
+/// is selected using [JwkSet::select].
+///
+/// ```rust
+/// # use jsonweb::jwk::JwkSet;
+/// let jwks: JwkSet = serde_json::from_str(r#"{
+///     "keys": [
+///         { "kty": "oct", "k": "c2VjcmV0", "alg": "HS256", "kid": "key-1" }
+///     ]
+/// }"#).unwrap();
+///
+/// assert!(jwks.find("key-1").is_some());
+/// ```
+///
+/// Keys that cannot be parsed, for instance because of an unknown `kty`, are skipped when the set
+/// is deserialized, as required by
+/// [RFC 7517 section 5](https://www.rfc-editor.org/rfc/rfc7517#section-5).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JwkSet {
+    #[serde(deserialize_with = "deserialize_keys")]
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    pub fn new(keys: Vec<Jwk>) -> Self {
+        JwkSet {
+            keys,
+        }
+    }
+
+    /// Returns the first key with the given `kid`.
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter()
+            .find(|jwk| jwk.kid.as_deref() == Some(kid))
+    }
+
+    /// Selects the key to verify a token with the given header. A key matches when:
+    ///
+    /// - the `kid` is the same as in the header, if the header has a `kid`;
+    /// - the `alg` is the same as in the header, if the key has an `alg`;
+    /// - the `use` is "sig" and the `key_ops` contain "verify", if these are set;
+    /// - the key type can be used with the `alg` in the header.
+    ///
+    /// Fails with [JwtError::NoMatchingKey] when no key matches and with [JwtError::AmbiguousKey]
+    /// when more than one key matches.
+    pub fn select(&self, header: &JwtHeader) -> Result<&Jwk, JwtError> {
+        let mut matching = self.keys.iter()
+            .filter(|jwk| JwkSet::matches(jwk, header));
+
+        let jwk = matching.next()
+            .ok_or(JwtError::NoMatchingKey)?;
+
+        if matching.next().is_some() {
+            return Err(JwtError::AmbiguousKey);
+        }
+
+        Ok(jwk)
+    }
+
+    fn matches(jwk: &Jwk, header: &JwtHeader) -> bool {
+        if header.kid.as_ref().is_some_and(|kid| jwk.kid.as_deref() != Some(kid)) {
+            return false;
+        }
+
+        if jwk.check_alg(&header.alg).is_err() {
+            return false;
+        }
+
+        if jwk.key_use.as_deref().is_some_and(|key_use| key_use != "sig") {
+            return false;
+        }
+
+        if jwk.key_ops.as_ref().is_some_and(|key_ops| !key_ops.iter().any(|op| op == "verify")) {
+            return false;
+        }
+
+        jwk.supports_alg(&header.alg)
+    }
+}
+
+/// Deserializes the keys of a set, skipping the keys that cannot be parsed.
+fn deserialize_keys<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Jwk>, D::Error> {
+    let keys = Vec::<Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter_map(|key| serde_json::from_value(key).ok())
+        .collect();
+
+    Ok(keys)
+}
+
+impl From<Vec<Jwk>> for JwkSet {
+    fn from(keys: Vec<Jwk>) -> Self {
+        JwkSet::new(keys)
+    }
+}
+
+impl JwKeyResolver for JwkSet {
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        let verifier = self.select(header)?
+            .verifier_for(&header.alg)
+            .map_err(|e| JwtError::AlgError(Box::new(e)))?;
+
+        verifier.verify_token(header, payload, signature)
+    }
+}
\ No newline at end of file
@@ -13,6 +13,21 @@ pub enum JwtError {
     #[error("JWT token does not specify the correct `alg` in the header")]
     AlgMismatch,
 
+    #[error("The `alg` in the header of the JWT token is not allowed")]
+    AlgNotAllowed,
+
+    #[error("No key matches the `kid` and `alg` in the header of the JWT token")]
+    NoMatchingKey,
+
+    #[error("More than one key matches the `kid` and `alg` in the header of the JWT token")]
+    AmbiguousKey,
+
+    #[error("The key matching the `kid` in the header of the JWT token is not valid at this time")]
+    KeyNotValid,
+
+    #[error("The JWT token uses critical header parameters that are not supported")]
+    UnsupportedCrit,
+
     #[error("No header")]
     NoHeader,
 
@@ -1,17 +1,27 @@
 mod models;
+mod traits;
 mod error;
 
 pub use models::jwt::Jwt;
 pub use models::jwt_claims::JwtClaims;
 pub use models::jwt_header::JwtHeader;
+pub use models::allowed_algs::AllowedAlgs;
+pub use models::key_ring::{ActiveKey, KeyRing};
+pub use models::unverified_jwt::UnverifiedJwt;
+pub use traits::jw_key_resolver::JwKeyResolver;
+pub use traits::clock::{Clock, SystemClock};
 pub use error::JwtError;
 
 #[cfg(test)]
 mod tests {
-    use crate::algorithm::{HS256Algorithm, JwAlg};
-    use crate::token::Jwt;
+    #[cfg(feature = "hs256")]
+    use crate::algorithm::{AnyAlgorithm, AnyVerifier, HS256Algorithm, NoneAlgorithm};
+
+    #[cfg(feature = "hs256")]
+    use crate::token::{Jwt, JwtError};
 
     #[test]
+    #[cfg(feature = "hs256")]
     fn simple_jwt_token_can_be_generated() {
         let algorithm = HS256Algorithm::new("something".as_bytes())
             .unwrap();
@@ -20,11 +30,12 @@ mod tests {
             .into_token(&algorithm)
             .unwrap();
 
-        let jwt = Jwt::<String>::check(&token, &algorithm)
+        Jwt::<String>::check(&token, &algorithm)
             .unwrap();
     }
 
     #[test]
+    #[cfg(feature = "hs256")]
     fn incorrect_signature_key() {
         let algorithm_1 = HS256Algorithm::new("something".as_bytes())
             .unwrap();
@@ -40,4 +51,429 @@ mod tests {
 
         assert!(jwt.is_err());
     }
+
+    #[test]
+    #[cfg(feature = "rs256")]
+    fn token_can_be_checked_using_only_the_public_key() {
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        use rsa::pkcs1v15::SigningKey;
+        use rsa::pkcs8::DecodePublicKey;
+        use rsa::{RsaPrivateKey, RsaPublicKey};
+        use crate::algorithm::{RS256Algorithm, RS256Verifier};
+        use crate::token::Jwt;
+
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../test-files/rs256.key")).unwrap();
+        let algorithm = RS256Algorithm::new(SigningKey::new(private_key));
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        let public_key = RsaPublicKey::from_public_key_pem(include_str!("../../../test-files/rs256.pub")).unwrap();
+        let verifier = RS256Verifier::new(public_key);
+
+        let jwt = Jwt::<String>::check(&token, &verifier)
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+        assert!(Jwt::<String>::check(&token, &algorithm.verifier()).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "es256")]
+    fn malformed_es256_signature_returns_an_error() {
+        use base64::Engine;
+        use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+        use p256::ecdsa::SigningKey;
+        use p256::SecretKey;
+        use crate::algorithm::ES256Algorithm;
+        use crate::token::{Jwt, JwtError};
+
+        let secret_key = include_str!("../../../test-files/es256.key").parse::<SecretKey>().unwrap();
+        let algorithm = ES256Algorithm::new(SigningKey::from(secret_key));
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        let (target, _) = token.rsplit_once('.').unwrap();
+
+        for signature in [vec![], vec![1; 12], vec![0xff; 64]] {
+            let tampered = format!("{}.{}", target, BASE64_URL_SAFE_NO_PAD.encode(signature));
+            let jwt = Jwt::<String>::check(&tampered, &algorithm);
+
+            assert!(matches!(jwt, Err(JwtError::AlgError(_))));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn any_algorithm_signs_using_the_configured_algorithm() {
+        let algorithm = AnyAlgorithm::from(HS256Algorithm::new("something".as_bytes()).unwrap());
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        assert!(token.starts_with("eyJhbGciOiJIUzI1NiIs"));
+
+        let jwt = Jwt::<String>::check(&token, &algorithm)
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn list_of_verifiers_uses_the_alg_from_the_header() {
+        let algorithm = HS256Algorithm::new("something".as_bytes())
+            .unwrap();
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        let verifiers = [
+            AnyVerifier::from(HS256Algorithm::new("else".as_bytes()).unwrap()),
+            AnyVerifier::from(algorithm),
+        ];
+
+        let jwt = Jwt::<String>::check(&token, &verifiers)
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+
+        let unsigned_token = Jwt::new("hello world".to_string())
+            .into_token(&NoneAlgorithm)
+            .unwrap();
+
+        let jwt = Jwt::<String>::check(&unsigned_token, &verifiers);
+
+        assert!(matches!(jwt, Err(JwtError::AlgMismatch)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "rs256", feature = "es256"))]
+    fn list_of_verifiers_accepts_tokens_from_different_algorithms() {
+        use p256::ecdsa::SigningKey as EcSigningKey;
+        use p256::SecretKey;
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        use rsa::pkcs1v15::SigningKey as RsaSigningKey;
+        use rsa::RsaPrivateKey;
+        use crate::algorithm::{AnyAlgorithm, ES256Algorithm, RS256Algorithm};
+        use crate::token::Jwt;
+
+        let private_key = RsaPrivateKey::from_pkcs1_pem(include_str!("../../../test-files/rs256.key")).unwrap();
+        let rs256 = AnyAlgorithm::from(RS256Algorithm::new(RsaSigningKey::new(private_key)));
+
+        let secret_key = include_str!("../../../test-files/es256.key").parse::<SecretKey>().unwrap();
+        let es256 = AnyAlgorithm::from(ES256Algorithm::new(EcSigningKey::from(secret_key)));
+
+        let verifiers = vec![rs256.verifier(), es256.verifier()];
+
+        for algorithm in [&rs256, &es256] {
+            let token = Jwt::new("hello world".to_string())
+                .into_token(algorithm)
+                .unwrap();
+
+            let jwt = Jwt::<String>::check(&token, &verifiers)
+                .unwrap();
+
+            assert_eq!(jwt.payload(), "hello world");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn disallowed_alg_is_rejected_even_when_a_key_exists() {
+        let algorithm = HS256Algorithm::new("something".as_bytes())
+            .unwrap();
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&algorithm)
+            .unwrap();
+
+        let jwt = Jwt::<String>::check_allowed(&token, &algorithm, &["HS384", "HS512"]);
+        assert!(matches!(jwt, Err(JwtError::AlgNotAllowed)));
+
+        let jwt = Jwt::<String>::check_allowed(&token, &algorithm, &["HS256"])
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+    }
+
+    /// The classic algorithm confusion attack: an attacker signs a token using HS256 using the
+    /// (public) RSA key of the server as the HMAC secret, hoping the server will use the `alg` from
+    /// the header together with the key it has.
+    #[test]
+    #[cfg(all(feature = "rs256", feature = "hs256"))]
+    fn rs256_public_key_cannot_be_used_as_hs256_secret() {
+        use base64::Engine;
+        use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+        use hmac::{Hmac, Mac};
+        use rsa::pkcs8::DecodePublicKey;
+        use rsa::RsaPublicKey;
+        use sha2::Sha256;
+        use crate::algorithm::{HmacKeyError, RS256Verifier};
+
+        let public_key_pem = include_str!("../../../test-files/rs256.pub");
+
+        let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+        let payload = BASE64_URL_SAFE_NO_PAD.encode(r#""hello world""#);
+        let target = format!("{}.{}", header, payload);
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(public_key_pem.as_bytes()).unwrap();
+        mac.update(target.as_bytes());
+        let signature = BASE64_URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        let forged_token = format!("{}.{}", target, signature);
+
+        // The public key can never be turned into an HMAC key.
+        assert!(matches!(
+            HS256Algorithm::new(public_key_pem.as_bytes()),
+            Err(HmacKeyError::PemEncodedKey)
+        ));
+
+        let verifier = RS256Verifier::new(RsaPublicKey::from_public_key_pem(public_key_pem).unwrap());
+        let keys = vec![AnyVerifier::from(verifier)];
+
+        let jwt = Jwt::<String>::check(&forged_token, &keys);
+        assert!(matches!(jwt, Err(JwtError::AlgMismatch)));
+
+        let jwt = Jwt::<String>::check_allowed(&forged_token, &keys, &["RS256"]);
+        assert!(matches!(jwt, Err(JwtError::AlgNotAllowed)));
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn header_parameters_can_be_set_when_issuing_and_read_after_checking() {
+        use crate::token::JwtHeader;
+
+        let algorithm = HS256Algorithm::new("something".as_bytes())
+            .unwrap();
+
+        let header = JwtHeader::default()
+            .with_typ("at+jwt")
+            .with_kid("key-1")
+            .with_jku("https://example.com/.well-known/jwks.json")
+            .with_x5t("dGhpcyBpcyBub3QgYSBjZXJ0aWZpY2F0ZQ")
+            .with_x5c(vec!["MIIB".to_string()])
+            .with_param("tenant", "acme");
+
+        let token = Jwt::new("hello world".to_string())
+            .with_header(header)
+            .into_token(&algorithm)
+            .unwrap();
+
+        let jwt = Jwt::<String>::check(&token, &algorithm)
+            .unwrap();
+
+        let header = jwt.header();
+
+        assert_eq!(header.alg, "HS256");
+        assert_eq!(header.typ.as_deref(), Some("at+jwt"));
+        assert_eq!(header.kid.as_deref(), Some("key-1"));
+        assert_eq!(header.jku.as_deref(), Some("https://example.com/.well-known/jwks.json"));
+        assert_eq!(header.x5t.as_deref(), Some("dGhpcyBpcyBub3QgYSBjZXJ0aWZpY2F0ZQ"));
+        assert_eq!(header.x5c, Some(vec!["MIIB".to_string()]));
+        assert_eq!(header.extra["tenant"], "acme");
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn tokens_without_typ_are_accepted_and_tokens_with_crit_are_rejected() {
+        use base64::Engine;
+        use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+        use crate::algorithm::JwSigner;
+
+        let algorithm = HS256Algorithm::new("something".as_bytes())
+            .unwrap();
+
+        let sign = |header: &str| {
+            let target = format!(
+                "{}.{}",
+                BASE64_URL_SAFE_NO_PAD.encode(header),
+                BASE64_URL_SAFE_NO_PAD.encode(r#""hello world""#),
+            );
+
+            format!("{}.{}", target, BASE64_URL_SAFE_NO_PAD.encode(algorithm.sign(&target)))
+        };
+
+        let jwt = Jwt::<String>::check(&sign(r#"{"alg":"HS256"}"#), &algorithm)
+            .unwrap();
+
+        assert_eq!(jwt.header().typ, None);
+
+        let jwt = Jwt::<String>::check(&sign(r#"{"alg":"HS256","crit":["exp"],"exp":1363284000}"#), &algorithm);
+        assert!(matches!(jwt, Err(JwtError::UnsupportedCrit)));
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn unverified_jwt_can_be_used_to_find_the_key() {
+        use serde_json::{json, Value};
+        use crate::token::{JwtHeader, UnverifiedJwt};
+
+        let algorithms = [
+            ("key-1", HS256Algorithm::new("something".as_bytes()).unwrap()),
+            ("key-2", HS256Algorithm::new("else".as_bytes()).unwrap()),
+        ];
+
+        let token = Jwt::new(json!({ "name": "hello world" }))
+            .with_header(JwtHeader::default().with_kid("key-2"))
+            .issuer("issuer")
+            .into_token(&algorithms[1].1)
+            .unwrap();
+
+        let unverified = UnverifiedJwt::<Value>::decode(&token)
+            .unwrap();
+
+        assert_eq!(unverified.header().alg, "HS256");
+        assert_eq!(unverified.claims().iss.as_deref(), Some("issuer"));
+        assert_eq!(unverified.payload()["name"], "hello world");
+
+        let kid = unverified.header().kid.as_deref().unwrap();
+        let (_, algorithm) = algorithms.iter()
+            .find(|(id, _)| *id == kid)
+            .unwrap();
+
+        let jwt = unverified.verify(algorithm)
+            .unwrap();
+
+        assert_eq!(jwt.payload()["name"], "hello world");
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn unverified_jwt_with_a_forged_signature_fails_verification() {
+        use crate::token::UnverifiedJwt;
+
+        let algorithm = HS256Algorithm::new("something".as_bytes())
+            .unwrap();
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&HS256Algorithm::new("else".as_bytes()).unwrap())
+            .unwrap();
+
+        // Decoding does not look at the signature at all.
+        let unverified = UnverifiedJwt::<String>::decode(&token)
+            .unwrap();
+
+        assert_eq!(unverified.payload(), "hello world");
+        assert!(matches!(unverified.verify(&algorithm), Err(JwtError::InvalidSignature)));
+    }
+
+    /// Clock that only moves when the test moves it.
+    #[cfg(feature = "hs256")]
+    #[derive(Clone)]
+    struct FakeClock(std::rc::Rc<std::cell::Cell<i64>>);
+
+    #[cfg(feature = "hs256")]
+    impl FakeClock {
+        fn advance(&self, duration: chrono::Duration) {
+            self.0.set(self.0.get() + duration.num_seconds());
+        }
+    }
+
+    #[cfg(feature = "hs256")]
+    impl crate::token::Clock for FakeClock {
+        fn now(&self) -> chrono::DateTime<chrono::Utc> {
+            chrono::DateTime::from_timestamp(self.0.get(), 0).unwrap()
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn key_ring_signs_with_the_active_key_and_stamps_the_kid() {
+        use base64::Engine;
+        use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+        use crate::token::{JwtHeader, KeyRing};
+
+        let ring = KeyRing::new("key-1", HS256Algorithm::generate());
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&ring.active())
+            .unwrap();
+
+        let (header, _) = token.split_once('.').unwrap();
+        let header: JwtHeader = serde_json::from_slice(&BASE64_URL_SAFE_NO_PAD.decode(header).unwrap()).unwrap();
+
+        assert_eq!(header.kid.as_deref(), Some("key-1"));
+
+        let jwt = Jwt::<String>::check(&token, &ring)
+            .unwrap();
+
+        assert_eq!(jwt.payload(), "hello world");
+
+        // Tokens without a `kid` or with an unknown `kid` are rejected.
+        let token = Jwt::new("hello world".to_string())
+            .into_token(ring.active().key())
+            .unwrap();
+
+        assert!(matches!(Jwt::<String>::check(&token, &ring), Err(JwtError::NoMatchingKey)));
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn key_ring_rotation_keeps_issued_tokens_valid_until_the_grace_period_ends() {
+        use chrono::Duration;
+        use crate::token::KeyRing;
+
+        let clock = FakeClock(Default::default());
+        clock.advance(Duration::days(10_000));
+
+        let mut ring = KeyRing::with_clock("key-1", HS256Algorithm::generate(), clock.clone());
+
+        let old_token = Jwt::new("old".to_string())
+            .into_token(&ring.active())
+            .unwrap();
+
+        clock.advance(Duration::days(30));
+        ring.rotate("key-2", HS256Algorithm::generate(), Duration::days(1));
+
+        let new_token = Jwt::new("new".to_string())
+            .into_token(&ring.active())
+            .unwrap();
+
+        assert!(Jwt::<String>::check(&old_token, &ring).is_ok());
+        assert!(Jwt::<String>::check(&new_token, &ring).is_ok());
+
+        clock.advance(Duration::hours(23));
+        assert!(Jwt::<String>::check(&old_token, &ring).is_ok());
+
+        clock.advance(Duration::hours(1));
+        assert!(matches!(Jwt::<String>::check(&old_token, &ring), Err(JwtError::KeyNotValid)));
+        assert!(Jwt::<String>::check(&new_token, &ring).is_ok());
+
+        // The retired key can be removed, but the active key cannot.
+        assert!(ring.remove("key-2").is_none());
+        assert!(ring.remove("key-1").is_some());
+        assert!(matches!(Jwt::<String>::check(&old_token, &ring), Err(JwtError::NoMatchingKey)));
+    }
+
+    #[test]
+    #[cfg(feature = "hs256")]
+    fn key_ring_rejects_tokens_before_the_not_before_time() {
+        use chrono::Duration;
+        use crate::token::{Clock, KeyRing};
+
+        let clock = FakeClock(Default::default());
+        clock.advance(Duration::days(10_000));
+
+        let mut ring = KeyRing::with_clock("key-1", HS256Algorithm::generate(), clock.clone());
+        ring.insert("key-2", HS256Algorithm::generate());
+        ring.set_not_before("key-2", clock.now() + Duration::hours(1)).unwrap();
+        ring.activate("key-2").unwrap();
+
+        let token = Jwt::new("hello world".to_string())
+            .into_token(&ring.active())
+            .unwrap();
+
+        assert!(matches!(Jwt::<String>::check(&token, &ring), Err(JwtError::KeyNotValid)));
+
+        clock.advance(Duration::hours(1));
+        assert!(Jwt::<String>::check(&token, &ring).is_ok());
+
+        assert!(matches!(ring.activate("key-3"), Err(JwtError::NoMatchingKey)));
+    }
 }
\ No newline at end of file
@@ -1,3 +1,6 @@
 pub mod jwt;
 pub mod jwt_header;
-pub mod jwt_claims;
\ No newline at end of file
+pub mod jwt_claims;
+pub mod allowed_algs;
+pub mod key_ring;
+pub mod unverified_jwt;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use crate::token::{JwKeyResolver, JwtError, JwtHeader};
+
+/// Only accepts tokens using one of the allowed algorithms before handing the token to the keys.
+/// The `alg` in the header is chosen by whoever created the token, so when verifying with keys for
+/// multiple algorithms this makes sure an attacker cannot pick a weaker or unexpected algorithm,
+/// even if a key for it exists.
+///
+/// ```
+/// # #[cfg(feature = "hs256")] {
+/// use jsonweb::algorithm::{AnyVerifier, HS256Algorithm};
+/// use jsonweb::token::{AllowedAlgs, Jwt, JwtError};
+///
+/// let algorithm = HS256Algorithm::new(b"secret").unwrap();
+/// let token = Jwt::new("hello".to_string()).into_token(&algorithm).unwrap();
+///
+/// let keys = vec![AnyVerifier::from(algorithm)];
+/// let result = Jwt::<String>::check(&token, &AllowedAlgs::new(&keys, &["RS256"]));
+///
+/// assert!(matches!(result, Err(JwtError::AlgNotAllowed)));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AllowedAlgs<'a, K: ?Sized> {
+    keys: &'a K,
+    allowed: &'a [&'a str],
+}
+
+impl<'a, K: ?Sized> AllowedAlgs<'a, K> {
+    pub fn new(keys: &'a K, allowed: &'a [&'a str]) -> Self {
+        AllowedAlgs {
+            keys,
+            allowed,
+        }
+    }
+}
+
+impl<K> JwKeyResolver for AllowedAlgs<'_, K>
+where K : JwKeyResolver + ?Sized,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        if !self.allowed.contains(&header.alg.as_ref()) {
+            return Err(JwtError::AlgNotAllowed);
+        }
+
+        self.keys.verify_token(header, payload, signature)
+    }
+}
\ No newline at end of file
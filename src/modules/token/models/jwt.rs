@@ -5,14 +5,15 @@ use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use crate::algorithm::JwAlg;
-use crate::token::{JwtError, JwtHeader};
+use crate::algorithm::JwSigner;
+use crate::token::{AllowedAlgs, JwKeyResolver, JwtError, JwtHeader, UnverifiedJwt};
 use crate::token::models::jwt_claims::JwtClaims;
 
 /// Representation of a (decoded) JWT token.
 pub struct Jwt<T>
 where T : Serialize + for<'a> Deserialize<'a>,
 {
+    header: JwtHeader<'static>,
     payload: T,
     claims: JwtClaims,
 }
@@ -20,35 +21,19 @@ where T : Serialize + for<'a> Deserialize<'a>,
 impl<T> Jwt<T>
 where T : Serialize + for<'a> Deserialize<'a>,
 {
-    /// Takes the JWT instance, signs it, and returns the string representation for the token.
-    pub fn into_token<A: JwAlg>(self, algorithm: &A) -> Result<String, JwtError> {
-        let alg_ref = A::alg();
-
+    /// Takes the JWT instance, signs it, and returns the string representation for the token. The
+    /// `alg` in the header is set to the algorithm's, as is the `kid` if the algorithm has one.
+    pub fn into_token<A: JwSigner>(self, algorithm: &A) -> Result<String, JwtError> {
         let header = JwtHeader {
-            alg: Cow::Borrowed(alg_ref.as_ref()),
-            typ: Cow::Borrowed("JWT"),
-            cty: None,
+            alg: Cow::Borrowed(algorithm.alg()),
+            kid: algorithm.kid().map(Cow::Borrowed).or(self.header.kid),
+            ..self.header
         };
 
         let header_bytes = serde_json::to_vec(&header)?;
         let header_string = BASE64_URL_SAFE_NO_PAD.encode(&header_bytes);
 
-        let mut json_value = serde_json::to_value(&self.payload)?;
-
-        if !self.claims.is_empty() {
-            let Some(payload_object) = json_value.as_object_mut() else {
-                return Err(JwtError::PayloadNotAnObject);
-            };
-
-            let mut claims_value = serde_json::to_value(&self.claims)?;
-            let claims_object = claims_value
-                .as_object_mut()
-                .expect("This should always result in an object");
-
-            payload_object.append(claims_object);
-        }
-
-        let bytes = serde_json::to_vec(&json_value)?;
+        let bytes = self.claims.to_payload(&self.payload)?;
         let payload_string = BASE64_URL_SAFE_NO_PAD.encode(&bytes);
 
         let target = format!("{}.{}", header_string, payload_string);
@@ -59,54 +44,37 @@ where T : Serialize + for<'a> Deserialize<'a>,
         Ok(format!("{}.{}", target, signature_string))
     }
 
-    /// Decodes and verifies the given string token with the given algorithm. Returns a JWT token
-    /// instance with the expected payload. Note that this does not check any claims. To verify
-    /// basic expiry claims you can use [Jwt::verify_now] or you can further verify the token using
-    /// [Jwt::against] or [Jwt::guard].
-    pub fn check<A: JwAlg>(token: &str, algorithm: &A) -> Result<Jwt<T>, JwtError>
-    where <A as JwAlg>::Error: 'static
-    {
-        let mut parts = token.split('.');
-
-        let header_string = parts.next().ok_or(JwtError::NoHeader)?;
-        let header_bytes = BASE64_URL_SAFE_NO_PAD.decode(header_string.as_bytes())?;
-        let header: JwtHeader = serde_json::from_slice(&header_bytes)?;
-
-        if header.alg != Cow::Borrowed(A::alg().as_ref()) {
-            return Err(JwtError::AlgMismatch);
-        }
-
-        let payload_string = parts.next().ok_or(JwtError::NoPayload)?;
-        let payload_bytes = BASE64_URL_SAFE_NO_PAD.decode(payload_string.as_bytes())?;
-        let payload: T = serde_json::from_slice(&payload_bytes)?;
-
-        // Unwrap or default as these would then be checked later.
-        let claims = serde_json::from_slice(&payload_bytes)
-            .unwrap_or_default();
-
-        let signature_string = parts.next().ok_or(JwtError::NoSignature)?;
-        let signature_bytes = BASE64_URL_SAFE_NO_PAD.decode(signature_string.as_bytes())?;
-
-        let target = format!("{}.{}", header_string, payload_string);
-        let verified = algorithm.verify(&target, &signature_bytes)
-            .map_err(|e| JwtError::AlgError(Box::new(e)))?;
-
-        if !verified {
-            return Err(JwtError::InvalidSignature);
-        }
-
-        Ok(Jwt {
-            payload,
-            claims,
-        })
+    /// Decodes and verifies the given string token with the given algorithm or keys. Returns a JWT
+    /// token instance with the expected payload. Note that this does not check any claims. To
+    /// verify basic expiry claims you can use [Jwt::verify_now] or you can further verify the token
+    /// using [Jwt::against] or [Jwt::guard].
+    ///
+    /// Passing a single algorithm only accepts tokens using that algorithm. To select the algorithm
+    /// at runtime, pass a list of [AnyVerifier](crate::algorithm::AnyVerifier)s or
+    /// [AnyAlgorithm](crate::algorithm::AnyAlgorithm)s and the ones matching the `alg` in the header
+    /// are used.
+    ///
+    /// To look at the header or claims before the key is known, use [UnverifiedJwt].
+    pub fn check<K: JwKeyResolver + ?Sized>(token: &str, keys: &K) -> Result<Jwt<T>, JwtError> {
+        UnverifiedJwt::decode(token)?
+            .verify(keys)
+    }
+
+    /// Same as [Jwt::check], but rejects the token with [JwtError::AlgNotAllowed] if the `alg` in
+    /// the header is not one of the allowed algorithms, even if one of the keys could verify it.
+    /// See [AllowedAlgs].
+    pub fn check_allowed<K: JwKeyResolver + ?Sized>(
+        token: &str,
+        keys: &K,
+        allowed: &[&str],
+    ) -> Result<Jwt<T>, JwtError> {
+        Jwt::<T>::check(token, &AllowedAlgs::new(keys, allowed))
     }
 
     /// Largely the same as [Jwt::check], but also verifies basic expiry claims. You can further
     /// verify the token using [Jwt::against] or [Jwt::guard].
-    pub fn verify_now<A: JwAlg>(token: &str, algorithm: &A) -> Result<Jwt<T>, JwtError>
-    where <A as JwAlg>::Error: 'static
-    {
-        let jwt = Jwt::<T>::check(token, algorithm)?
+    pub fn verify_now<K: JwKeyResolver + ?Sized>(token: &str, keys: &K) -> Result<Jwt<T>, JwtError> {
+        let jwt = Jwt::<T>::check(token, keys)?
             .against(&JwtClaims::now())?;
 
         Ok(jwt)
@@ -124,6 +92,13 @@ where T : Serialize + for<'a> Deserialize<'a>,
         self.claims.verify(other)
     }
 
+    /// Overwrites the header that is used when issuing the token. See [JwtHeader::default] for the
+    /// default header.
+    pub fn with_header(mut self, header: JwtHeader<'static>) -> Self {
+        self.header = header;
+        self
+    }
+
     /// Overwrites the current claims with the given claims.
     pub fn with_claims(mut self, claims: JwtClaims) -> Self {
         self.claims = claims;
@@ -195,6 +170,11 @@ where T : Serialize + for<'a> Deserialize<'a>,
         self
     }
 
+    /// Returns the header of the token. For a checked token this is the header it was signed with.
+    pub fn header(&self) -> &JwtHeader<'static> {
+        &self.header
+    }
+
     /// Returns a reference to the payload for this token.
     pub fn payload(&self) -> &T {
         &self.payload
@@ -216,17 +196,27 @@ impl<T> Debug for Jwt<T>
 where T : Serialize + for<'a> Deserialize<'a> + Debug,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Jwt {{ claims: {:?}, payload: {:?} }}", self.claims, self.payload)
+        write!(f, "Jwt {{ header: {:?}, claims: {:?}, payload: {:?} }}", self.header, self.claims, self.payload)
     }
 }
 
 impl<T> Jwt<T>
 where T : Serialize + for<'a> Deserialize<'a>
 {
+    /// Only used by [UnverifiedJwt::verify], after the signature has been verified.
+    pub(crate) fn from_verified(header: JwtHeader<'static>, payload: T, claims: JwtClaims) -> Self {
+        Jwt {
+            header,
+            payload,
+            claims,
+        }
+    }
+
     pub fn new(payload: T) -> Self {
         Jwt {
+            header: JwtHeader::default(),
             payload,
             claims: JwtClaims::default(),
         }
     }
-}
+}
\ No newline at end of file
@@ -178,15 +178,15 @@ impl JwtClaims {
     /// Overwrites the current values with the other values, copying the ones that are set.
     pub fn with_merge(mut self, other: &JwtClaims) -> JwtClaims {
         if let Some(nbf) = &other.nbf {
-            self.nbf = Some(nbf.clone());
+            self.nbf = Some(*nbf);
         }
 
         if let Some(exp) = &other.exp {
-            self.exp = Some(exp.clone());
+            self.exp = Some(*exp);
         }
 
         if let Some(iat) = &other.iat {
-            self.iat = Some(iat.clone());
+            self.iat = Some(*iat);
         }
 
         if let Some(iss ) = &other.iss {
@@ -204,6 +204,27 @@ impl JwtClaims {
         self
     }
 
+    /// Serializes the payload with these claims added to it. The payload must serialize to a JSON
+    /// object, unless there are no claims.
+    pub(crate) fn to_payload<T: Serialize>(&self, payload: &T) -> Result<Vec<u8>, JwtError> {
+        let mut json_value = serde_json::to_value(payload)?;
+
+        if !self.is_empty() {
+            let Some(payload_object) = json_value.as_object_mut() else {
+                return Err(JwtError::PayloadNotAnObject);
+            };
+
+            let mut claims_value = serde_json::to_value(self)?;
+            let claims_object = claims_value
+                .as_object_mut()
+                .expect("This should always result in an object");
+
+            payload_object.append(claims_object);
+        }
+
+        Ok(serde_json::to_vec(&json_value)?)
+    }
+
     pub fn verify(&self, other: &JwtClaims) -> Result<(), JwtError> {
         if let Some(expected) = &other.nbf {
             let Some(received) = &self.nbf else {
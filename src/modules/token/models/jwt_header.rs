@@ -1,20 +1,152 @@
 use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::jwk::Jwk;
 
 /// The header of a JWT token. Used to identify what signing algorithm is used and what type of
-/// token it is.
+/// token it is. Contains the registered header parameters from
+/// [RFC 7515 section 4.1](https://www.rfc-editor.org/rfc/rfc7515#section-4.1), any other
+/// parameters end up in [JwtHeader::extra].
+///
+/// To set header parameters when issuing a token, pass the header to
+/// [Jwt::with_header](crate::token::Jwt::with_header). The `alg` is always set by the algorithm that
+/// signs the token.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JwtHeader<'a> {
     /// The algorithm of that the server used to sign the JWT token. Possible values can be found in
     /// [RFC 7518](https://www.rfc-editor.org/rfc/rfc7518#section-3).
     pub alg: Cow<'a, str>,
 
-    /// The type of token. This is probably here for future-proofing as currently it should always
-    /// be "JWT".
-    pub typ: Cow<'a, str>,
+    /// The type of token. Defaults to "JWT" when issuing tokens, but other specifications define
+    /// their own types like "at+jwt" for access tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typ: Option<Cow<'a, str>>,
 
     /// This is usually used when using nested JWT tokens, but here it's used to differentiate
     /// between access tokens and refresh tokens.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cty: Option<Cow<'a, str>>,
+
+    /// The ID of the key that was used to sign the token, so the receiver can pick the matching key
+    /// from a [JwkSet](crate::jwk::JwkSet).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<Cow<'a, str>>,
+
+    /// URL of the JWK Set that contains the key used to sign the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jku: Option<Cow<'a, str>>,
+
+    /// The public key used to sign the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwk: Option<Jwk>,
+
+    /// URL of the X.509 certificate (chain) of the key used to sign the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x5u: Option<Cow<'a, str>>,
+
+    /// The X.509 certificate chain of the key used to sign the token, as base64 (not base64url)
+    /// encoded DER certificates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x5c: Option<Vec<String>>,
+
+    /// Base64url encoded SHA-1 thumbprint of the X.509 certificate of the key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x5t: Option<Cow<'a, str>>,
+
+    /// Base64url encoded SHA-256 thumbprint of the X.509 certificate of the key.
+    #[serde(rename = "x5t#S256", default, skip_serializing_if = "Option::is_none")]
+    pub x5t_s256: Option<Cow<'a, str>>,
+
+    /// Header parameters that the receiver must understand. As none of the extensions are
+    /// supported, tokens with this parameter are rejected with [JwtError::UnsupportedCrit](crate::token::JwtError::UnsupportedCrit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crit: Option<Vec<String>>,
+
+    /// Any other (private) header parameters.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JwtHeader<'_> {
+    /// Sets the `typ` parameter on the header.
+    pub fn with_typ(mut self, typ: impl Into<String>) -> Self {
+        self.typ = Some(Cow::Owned(typ.into()));
+        self
+    }
+
+    /// Sets the `cty` parameter on the header.
+    pub fn with_cty(mut self, cty: impl Into<String>) -> Self {
+        self.cty = Some(Cow::Owned(cty.into()));
+        self
+    }
+
+    /// Sets the `kid` parameter on the header. This is overwritten by the `kid` of the algorithm if
+    /// it has one, like the active key of a [KeyRing](crate::token::KeyRing).
+    pub fn with_kid(mut self, kid: impl Into<String>) -> Self {
+        self.kid = Some(Cow::Owned(kid.into()));
+        self
+    }
+
+    /// Sets the `jku` parameter on the header.
+    pub fn with_jku(mut self, jku: impl Into<String>) -> Self {
+        self.jku = Some(Cow::Owned(jku.into()));
+        self
+    }
+
+    /// Sets the `jwk` parameter on the header. Make sure this does not contain the private key.
+    pub fn with_jwk(mut self, jwk: Jwk) -> Self {
+        self.jwk = Some(jwk);
+        self
+    }
+
+    /// Sets the `x5u` parameter on the header.
+    pub fn with_x5u(mut self, x5u: impl Into<String>) -> Self {
+        self.x5u = Some(Cow::Owned(x5u.into()));
+        self
+    }
+
+    /// Sets the `x5c` parameter on the header.
+    pub fn with_x5c(mut self, x5c: Vec<String>) -> Self {
+        self.x5c = Some(x5c);
+        self
+    }
+
+    /// Sets the `x5t` parameter on the header.
+    pub fn with_x5t(mut self, x5t: impl Into<String>) -> Self {
+        self.x5t = Some(Cow::Owned(x5t.into()));
+        self
+    }
+
+    /// Sets the `x5t#S256` parameter on the header.
+    pub fn with_x5t_s256(mut self, x5t_s256: impl Into<String>) -> Self {
+        self.x5t_s256 = Some(Cow::Owned(x5t_s256.into()));
+        self
+    }
+
+    /// Sets a custom parameter on the header.
+    pub fn with_param(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// The header used when issuing tokens, which only has `typ` set to "JWT". The `alg` is empty until
+/// the token is signed.
+impl Default for JwtHeader<'_> {
+    fn default() -> Self {
+        JwtHeader {
+            alg: Cow::Borrowed(""),
+            typ: Some(Cow::Borrowed("JWT")),
+            cty: None,
+            kid: None,
+            jku: None,
+            jwk: None,
+            x5u: None,
+            x5c: None,
+            x5t: None,
+            x5t_s256: None,
+            crit: None,
+            extra: Map::new(),
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,231 @@
+use std::fmt::{Debug, Formatter};
+use chrono::{DateTime, Duration, Utc};
+use crate::algorithm::{JwSigner, JwVerifier};
+use crate::token::{Clock, JwKeyResolver, JwtError, JwtHeader, SystemClock};
+
+/// Set of keys identified by their `kid`, used to rotate signing keys without invalidating the
+/// tokens that were already issued. New tokens are signed using the [active](KeyRing::active) key,
+/// which also puts its `kid` in the header. When verifying, the key is selected using the `kid` in
+/// the header and the token is only accepted if the current time is within the validity window of
+/// that key.
+///
+/// ```
+/// # #[cfg(feature = "hs256")] {
+/// use chrono::Duration;
+/// use jsonweb::algorithm::HS256Algorithm;
+/// use jsonweb::token::{Jwt, KeyRing};
+///
+/// let mut ring = KeyRing::new("key-1", HS256Algorithm::generate());
+/// let token = Jwt::new("hello".to_string()).into_token(&ring.active()).unwrap();
+///
+/// // Tokens signed by the previous key are accepted for another day.
+/// ring.rotate("key-2", HS256Algorithm::generate(), Duration::days(1));
+///
+/// assert!(Jwt::<String>::check(&token, &ring).is_ok());
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyRing<A, C = SystemClock> {
+    keys: Vec<KeyRingEntry<A>>,
+    active: String,
+    clock: C,
+}
+
+#[derive(Debug, Clone)]
+struct KeyRingEntry<A> {
+    kid: String,
+    key: A,
+    not_before: Option<DateTime<Utc>>,
+    not_after: Option<DateTime<Utc>>,
+}
+
+impl<A> KeyRingEntry<A> {
+    fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        self.not_before.is_none_or(|not_before| now >= not_before)
+            && self.not_after.is_none_or(|not_after| now < not_after)
+    }
+}
+
+impl<A> KeyRing<A> {
+    /// Creates the key ring with the given key as the active key.
+    pub fn new(kid: impl Into<String>, key: A) -> Self {
+        KeyRing::with_clock(kid, key, SystemClock)
+    }
+}
+
+impl<A, C: Clock> KeyRing<A, C> {
+    /// Same as [KeyRing::new], but uses the given clock to check the validity windows of the keys.
+    pub fn with_clock(kid: impl Into<String>, key: A, clock: C) -> Self {
+        let mut ring = KeyRing {
+            keys: vec![],
+            active: kid.into(),
+            clock,
+        };
+
+        ring.insert(ring.active.clone(), key);
+        ring
+    }
+
+    /// Adds a key that can be used to verify tokens, without a validity window. Replaces the key
+    /// if there already is a key with the same `kid`.
+    pub fn insert(&mut self, kid: impl Into<String>, key: A) {
+        let entry = KeyRingEntry {
+            kid: kid.into(),
+            key,
+            not_before: None,
+            not_after: None,
+        };
+
+        match self.keys.iter_mut().find(|existing| existing.kid == entry.kid) {
+            Some(existing) => *existing = entry,
+            None => self.keys.push(entry),
+        }
+    }
+
+    /// Makes the key with the given `kid` the active key. Fails with [JwtError::NoMatchingKey] if
+    /// there is no such key.
+    pub fn activate(&mut self, kid: &str) -> Result<(), JwtError> {
+        let entry = self.entry(kid)
+            .ok_or(JwtError::NoMatchingKey)?;
+
+        self.active = entry.kid.clone();
+        Ok(())
+    }
+
+    /// Adds the key and makes it the active key. The previously active key keeps verifying the
+    /// tokens it signed until `grace` has passed, so the grace period should be at least the
+    /// lifetime of the issued tokens.
+    pub fn rotate(&mut self, kid: impl Into<String>, key: A, grace: Duration) {
+        let kid = kid.into();
+        let retired_at = self.clock.now() + grace;
+
+        let previous = std::mem::replace(&mut self.active, kid.clone());
+
+        if let Some(entry) = self.entry_mut(&previous) {
+            entry.not_after = Some(retired_at);
+        }
+
+        self.insert(kid, key);
+    }
+
+    /// Tokens signed by the key with the given `kid` are rejected before this time.
+    pub fn set_not_before(&mut self, kid: &str, not_before: DateTime<Utc>) -> Result<(), JwtError> {
+        let entry = self.entry_mut(kid)
+            .ok_or(JwtError::NoMatchingKey)?;
+
+        entry.not_before = Some(not_before);
+        Ok(())
+    }
+
+    /// Tokens signed by the key with the given `kid` are rejected from this time on.
+    pub fn set_not_after(&mut self, kid: &str, not_after: DateTime<Utc>) -> Result<(), JwtError> {
+        let entry = self.entry_mut(kid)
+            .ok_or(JwtError::NoMatchingKey)?;
+
+        entry.not_after = Some(not_after);
+        Ok(())
+    }
+
+    /// Removes the key with the given `kid`. The active key cannot be removed, so this returns
+    /// `None` for the active key until another key is activated.
+    pub fn remove(&mut self, kid: &str) -> Option<A> {
+        if kid == self.active {
+            return None;
+        }
+
+        let index = self.keys.iter()
+            .position(|entry| entry.kid == kid)?;
+
+        Some(self.keys.remove(index).key)
+    }
+
+    /// Returns the key with the given `kid`, regardless of its validity window.
+    pub fn get(&self, kid: &str) -> Option<&A> {
+        self.entry(kid)
+            .map(|entry| &entry.key)
+    }
+
+    /// Returns the active key, which can be passed to [Jwt::into_token](crate::token::Jwt::into_token)
+    /// to sign a token with its `kid` in the header.
+    pub fn active(&self) -> ActiveKey<'_, A> {
+        let entry = self.entry(&self.active)
+            .expect("The active key is never removed");
+
+        ActiveKey {
+            kid: &entry.kid,
+            key: &entry.key,
+        }
+    }
+
+    fn entry(&self, kid: &str) -> Option<&KeyRingEntry<A>> {
+        self.keys.iter()
+            .find(|entry| entry.kid == kid)
+    }
+
+    fn entry_mut(&mut self, kid: &str) -> Option<&mut KeyRingEntry<A>> {
+        self.keys.iter_mut()
+            .find(|entry| entry.kid == kid)
+    }
+}
+
+/// Selects the key using the `kid` in the header. Fails with [JwtError::NoMatchingKey] if the
+/// header has no `kid` or the key is unknown, and with [JwtError::KeyNotValid] if the current time
+/// is outside the validity window of the key.
+impl<A, C> JwKeyResolver for KeyRing<A, C>
+where A : JwVerifier,
+      <A as JwVerifier>::Error: 'static,
+      C : Clock,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        let entry = header.kid.as_deref()
+            .and_then(|kid| self.entry(kid))
+            .ok_or(JwtError::NoMatchingKey)?;
+
+        if !entry.is_valid_at(self.clock.now()) {
+            return Err(JwtError::KeyNotValid);
+        }
+
+        entry.key.verify_token(header, payload, signature)
+    }
+}
+
+/// The active key of a [KeyRing], which signs tokens using the `kid` it has in the key ring.
+pub struct ActiveKey<'a, A> {
+    kid: &'a str,
+    key: &'a A,
+}
+
+impl<'a, A> ActiveKey<'a, A> {
+    /// Returns the key that is used to sign.
+    pub fn key(&self) -> &'a A {
+        self.key
+    }
+}
+
+impl<A: JwVerifier> JwVerifier for ActiveKey<'_, A> {
+    type Error = A::Error;
+
+    fn alg(&self) -> &str {
+        self.key.alg()
+    }
+
+    fn verify(&self, payload: &str, signature: &[u8]) -> Result<bool, Self::Error> {
+        self.key.verify(payload, signature)
+    }
+}
+
+impl<A: JwSigner> JwSigner for ActiveKey<'_, A> {
+    fn sign(&self, payload: &str) -> Vec<u8> {
+        self.key.sign(payload)
+    }
+
+    fn kid(&self) -> Option<&str> {
+        Some(self.kid)
+    }
+}
+
+impl<A> Debug for ActiveKey<'_, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActiveKey {{ kid: {:?}, .. }}", self.kid)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::fmt::{Debug, Formatter};
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use crate::token::{JwKeyResolver, Jwt, JwtClaims, JwtError, JwtHeader};
+
+/// A token that has been decoded, but of which the signature has **not** been verified. Anyone can
+/// create a token with any header and claims, so nothing in here can be trusted. This is only
+/// meant to look at the token before verifying it, for example to find the key using the `kid` or
+/// `iss`.
+///
+/// The only way to get a [Jwt] from this is [UnverifiedJwt::verify], which does the same checks as
+/// [Jwt::check].
+///
+/// ```
+/// # #[cfg(feature = "hs256")] {
+/// use jsonweb::algorithm::HS256Algorithm;
+/// use jsonweb::token::{Jwt, UnverifiedJwt};
+/// use serde_json::{json, Value};
+///
+/// let algorithm = HS256Algorithm::new(b"secret").unwrap();
+/// let token = Jwt::new(json!({})).issuer("issuer").into_token(&algorithm).unwrap();
+///
+/// let unverified = UnverifiedJwt::<Value>::decode(&token).unwrap();
+/// assert_eq!(unverified.claims().iss.as_deref(), Some("issuer"));
+///
+/// let jwt = unverified.verify(&algorithm).unwrap();
+/// assert_eq!(jwt.payload()["iss"], "issuer");
+/// # }
+/// ```
+///
+/// There is no conversion that skips the verification:
+///
+/// ```compile_fail
+/// # use jsonweb::token::{Jwt, UnverifiedJwt};
+/// let jwt: Jwt<String> = UnverifiedJwt::<String>::decode("").unwrap().into();
+/// ```
+pub struct UnverifiedJwt<T>
+where T : Serialize + for<'a> Deserialize<'a>,
+{
+    header: JwtHeader<'static>,
+    payload: T,
+    claims: JwtClaims,
+    target: String,
+    signature: Vec<u8>,
+}
+
+impl<T> UnverifiedJwt<T>
+where T : Serialize + for<'a> Deserialize<'a>,
+{
+    /// Decodes the token without verifying the signature.
+    pub fn decode(token: &str) -> Result<UnverifiedJwt<T>, JwtError> {
+        let mut parts = token.split('.');
+
+        let header_string = parts.next().ok_or(JwtError::NoHeader)?;
+        let header_bytes = BASE64_URL_SAFE_NO_PAD.decode(header_string.as_bytes())?;
+        let header: JwtHeader<'static> = serde_json::from_slice(&header_bytes)?;
+
+        let payload_string = parts.next().ok_or(JwtError::NoPayload)?;
+        let payload_bytes = BASE64_URL_SAFE_NO_PAD.decode(payload_string.as_bytes())?;
+        let payload: T = serde_json::from_slice(&payload_bytes)?;
+
+        // Unwrap or default as these would then be checked later.
+        let claims = serde_json::from_slice(&payload_bytes)
+            .unwrap_or_default();
+
+        let signature_string = parts.next().ok_or(JwtError::NoSignature)?;
+        let signature = BASE64_URL_SAFE_NO_PAD.decode(signature_string.as_bytes())?;
+
+        Ok(UnverifiedJwt {
+            header,
+            payload,
+            claims,
+            target: format!("{}.{}", header_string, payload_string),
+            signature,
+        })
+    }
+
+    /// Verifies the signature with the given algorithm or keys, see [Jwt::check].
+    pub fn verify<K: JwKeyResolver + ?Sized>(self, keys: &K) -> Result<Jwt<T>, JwtError> {
+        // None of the header extensions are supported, so these tokens must be rejected.
+        if self.header.crit.is_some() {
+            return Err(JwtError::UnsupportedCrit);
+        }
+
+        keys.verify_token(&self.header, &self.target, &self.signature)?;
+
+        Ok(Jwt::from_verified(self.header, self.payload, self.claims))
+    }
+
+    /// Returns the unverified header.
+    pub fn header(&self) -> &JwtHeader<'static> {
+        &self.header
+    }
+
+    /// Returns the unverified claims. Claims that could not be decoded are `None`.
+    pub fn claims(&self) -> &JwtClaims {
+        &self.claims
+    }
+
+    /// Returns the unverified payload.
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+}
+
+impl<T> Debug for UnverifiedJwt<T>
+where T : Serialize + for<'a> Deserialize<'a> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UnverifiedJwt {{ header: {:?}, claims: {:?}, payload: {:?} }}", self.header, self.claims, self.payload)
+    }
+}
\ No newline at end of file
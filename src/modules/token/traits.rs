@@ -0,0 +1,2 @@
+pub mod jw_key_resolver;
+pub mod clock;
\ No newline at end of file
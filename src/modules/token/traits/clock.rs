@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+/// Source of the current time, used by [KeyRing](crate::token::KeyRing) to check the validity
+/// windows of its keys. Can be replaced in tests to control the time.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// [Clock] that uses the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use crate::algorithm::JwVerifier;
+use crate::token::{JwtError, JwtHeader};
+
+/// Verifies the signature of a token using the key that belongs to the token's header. A single
+/// [JwVerifier] only accepts tokens that use its own `alg`, while a list of verifiers (for example
+/// [AnyVerifier](crate::algorithm::AnyVerifier)s) picks the ones matching the `alg` at runtime.
+pub trait JwKeyResolver {
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError>;
+}
+
+impl<V> JwKeyResolver for V
+where V : JwVerifier,
+      <V as JwVerifier>::Error: 'static,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        if header.alg != self.alg() {
+            return Err(JwtError::AlgMismatch);
+        }
+
+        let verified = self.verify(payload, signature)
+            .map_err(|e| JwtError::AlgError(Box::new(e)))?;
+
+        if !verified {
+            return Err(JwtError::InvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+/// Tries every verifier that uses the `alg` from the header and accepts the token if any of them
+/// verifies the signature.
+impl<V> JwKeyResolver for [V]
+where V : JwVerifier,
+      <V as JwVerifier>::Error: 'static,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        let mut result = Err(JwtError::AlgMismatch);
+
+        for verifier in self.iter().filter(|verifier| header.alg == verifier.alg()) {
+            result = verifier.verify_token(header, payload, signature);
+
+            if result.is_ok() {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl<V, const N: usize> JwKeyResolver for [V; N]
+where V : JwVerifier,
+      <V as JwVerifier>::Error: 'static,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        self.as_slice().verify_token(header, payload, signature)
+    }
+}
+
+impl<V> JwKeyResolver for Vec<V>
+where V : JwVerifier,
+      <V as JwVerifier>::Error: 'static,
+{
+    fn verify_token(&self, header: &JwtHeader, payload: &str, signature: &[u8]) -> Result<(), JwtError> {
+        self.as_slice().verify_token(header, payload, signature)
+    }
+}
\ No newline at end of file